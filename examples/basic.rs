@@ -1,73 +1,132 @@
 use ratatui::{
-    crossterm::event::{self, Event, KeyCode},
+    crossterm::{
+        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+        execute,
+    },
     prelude::{Buffer, Constraint, Layout, Rect, StatefulWidget, Stylize, Widget},
-    widgets::{Block, Paragraph},
+    widgets::{Block, Paragraph, Wrap},
 };
-use tui_menu::{Menu, MenuEvent, MenuItem, MenuState};
+use tui_menu::{CommandPalette, Menu, MenuEvent, MenuItem, MenuKeymap, MenuState, PaletteState};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
-    ratatui::run(|t| App::new().run(t))?;
+    execute!(std::io::stdout(), EnableMouseCapture)?;
+    let result = ratatui::run(|t| App::new().run(t));
+    execute!(std::io::stdout(), DisableMouseCapture)?;
+    result?;
     Ok(())
 }
 
 struct App {
     content: String,
+    status: String,
+    clipboard: Clipboard,
+    word_wrap: bool,
+    light_theme: bool,
     menu: MenuState<Action>,
+    palette: PaletteState,
 }
 
+/// app context which enables "Paste"
+#[derive(Default)]
+struct Clipboard(String);
+
 impl App {
     fn new() -> Self {
         Self {
             content: String::new(),
+            status: String::new(),
+            clipboard: Clipboard::default(),
+            word_wrap: false,
+            light_theme: false,
             menu: MenuState::new(vec![
                 MenuItem::group(
-                    "File",
+                    "&File",
                     vec![
-                        MenuItem::item("New", Action::FileNew),
-                        MenuItem::item("Open", Action::FileOpen),
+                        MenuItem::item("&New", Action::FileNew)
+                            .shortcut("Ctrl+N")
+                            .description("Start with an empty buffer"),
+                        MenuItem::item("&Open", Action::FileOpen).shortcut("Ctrl+O"),
                         MenuItem::group(
-                            "Open recent",
-                            ["file_1.txt", "file_2.txt"]
-                                .iter()
-                                .map(|&f| MenuItem::item(f, Action::FileOpenRecent(f.into())))
+                            "Open &recent",
+                            (1..=30)
+                                .map(|i| format!("file_{i}.txt"))
+                                .map(|f| MenuItem::item(f.clone(), Action::FileOpenRecent(f)))
                                 .collect(),
                         ),
-                        MenuItem::item("Save as", Action::FileSaveAs),
-                        MenuItem::item("Exit", Action::Exit),
+                        // listed on open, e.g. entries from slow disk
+                        MenuItem::lazy("Open &folder", |sender| {
+                            std::thread::spawn(move || {
+                                std::thread::sleep(std::time::Duration::from_millis(500));
+                                let entries = std::fs::read_dir(".")
+                                    .into_iter()
+                                    .flatten()
+                                    .flatten()
+                                    .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                                    .map(|name| {
+                                        MenuItem::item(name.clone(), Action::FileOpenFolder(name))
+                                    })
+                                    .collect();
+                                sender.send(entries);
+                            });
+                        })
+                        .cache(false),
+                        MenuItem::item("Save &as", Action::FileSaveAs)
+                            .description("Save the current buffer to disk"),
+                        MenuItem::separator(),
+                        MenuItem::item("E&xit", Action::Exit).shortcut("Ctrl+Q"),
                     ],
                 ),
                 MenuItem::group(
-                    "Edit",
+                    "&Edit",
                     vec![
-                        MenuItem::item("Copy", Action::EditCopy),
-                        MenuItem::item("Cut", Action::EditCut),
-                        MenuItem::item("Paste", Action::EditPaste),
+                        MenuItem::item("&Copy", Action::EditCopy)
+                            .shortcut("Ctrl+C")
+                            .description("Copy content to clipboard"),
+                        MenuItem::item("Cu&t", Action::EditCut).shortcut("Ctrl+X"),
+                        MenuItem::item("&Paste", Action::EditPaste)
+                            .shortcut("Ctrl+V")
+                            .enabled_when(|clipboard: &Clipboard| !clipboard.0.is_empty()),
                     ],
                 ),
                 MenuItem::group(
-                    "About",
+                    "&View",
                     vec![
-                        MenuItem::item("Author", Action::AboutAuthor),
-                        MenuItem::item("Help", Action::AboutHelp),
+                        MenuItem::checkbox("&Word wrap", Action::ViewWordWrap, false),
+                        MenuItem::section("Theme"),
+                        MenuItem::radio("&Light", "theme", Action::ViewLightTheme, false),
+                        MenuItem::radio("&Dark", "theme", Action::ViewDarkTheme, true),
                     ],
                 ),
-            ]),
+                MenuItem::group(
+                    "A&bout",
+                    vec![
+                        MenuItem::item("&Author", Action::AboutAuthor),
+                        MenuItem::item("H&elp", Action::AboutHelp),
+                    ],
+                ),
+            ])
+            .keymap(MenuKeymap::vim()),
+            palette: PaletteState::new(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 enum Action {
     FileNew,
     FileOpen,
     FileOpenRecent(String),
+    FileOpenFolder(String),
     FileSaveAs,
     Exit,
     EditCopy,
     EditCut,
     EditPaste,
+    ViewWordWrap,
+    ViewLightTheme,
+    ViewDarkTheme,
     AboutAuthor,
     AboutHelp,
 }
@@ -75,11 +134,15 @@ enum Action {
 impl App {
     fn run(mut self, terminal: &mut ratatui::DefaultTerminal) -> std::io::Result<()> {
         loop {
+            self.menu.update_context(&self.clipboard);
             terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
 
             if event::poll(std::time::Duration::from_millis(10))? {
-                if let Event::Key(key) = event::read()? {
-                    self.on_key_event(key);
+                match event::read()? {
+                    Event::Key(key) => self.on_key_event(key),
+                    event => {
+                        self.menu.handle_event(&event);
+                    }
                 }
             }
 
@@ -94,27 +157,81 @@ impl App {
                         }
                         Action::FileOpenRecent(file) => {
                             self.content = format!("content of {file}");
+                            // most recent first
+                            let recent = Action::FileOpenRecent(file);
+                            let from = self.menu.find(|action| *action == recent);
+                            let recent_group = self.menu.find_by_names(&["File", "Open recent"]);
+                            if let (Some(from), Some(group)) = (from, recent_group) {
+                                self.menu.move_item(&from, &group.child(0));
+                            }
+                        }
+                        Action::ViewLightTheme => self.light_theme = true,
+                        Action::ViewDarkTheme => self.light_theme = false,
+                        Action::EditCopy => {
+                            self.clipboard.0 = self.content.clone();
+                        }
+                        Action::EditPaste => {
+                            self.content.push_str(&self.clipboard.0);
                         }
                         action => {
                             self.content = format!("{action:?} not implemented");
                         }
                     },
+                    MenuEvent::Toggled(Action::ViewWordWrap, checked) => {
+                        self.word_wrap = checked;
+                    }
+                    MenuEvent::Toggled(action, checked) => {
+                        self.content = format!("{action:?} toggled to {checked}");
+                    }
+                    MenuEvent::DisabledActivated(action) => {
+                        self.content = format!("{action:?} is disabled");
+                    }
+                    MenuEvent::Highlighted(action) => {
+                        self.status = match self.menu.highlight_description() {
+                            Some(description) => description.to_string(),
+                            None => format!("{action:?}"),
+                        };
+                        continue;
+                    }
+                    MenuEvent::Cancelled => {
+                        self.status.clear();
+                        continue;
+                    }
+                    // group open and close
+                    _ => continue,
                 }
+                // close the menu once the item has been handled
+                self.status.clear();
                 self.menu.reset();
             }
         }
     }
 
     fn on_key_event(&mut self, key: event::KeyEvent) {
-        match key.code {
-            KeyCode::Char('h') | KeyCode::Left => self.menu.left(),
-            KeyCode::Char('l') | KeyCode::Right => self.menu.right(),
-            KeyCode::Char('j') | KeyCode::Down => self.menu.down(),
-            KeyCode::Char('k') | KeyCode::Up => self.menu.up(),
-            KeyCode::Esc => self.menu.reset(),
-            KeyCode::Enter => self.menu.select(),
-            _ => {}
+        if self.palette.is_open() {
+            match key.code {
+                KeyCode::Esc => self.palette.close(),
+                KeyCode::Enter => {
+                    self.palette.confirm(&mut self.menu);
+                }
+                KeyCode::Up => self.palette.up(),
+                KeyCode::Down => self.palette.down(),
+                KeyCode::Backspace => self.palette.pop_char(),
+                KeyCode::Char(c) => self.palette.push_char(c),
+                _ => {}
+            }
+            return;
+        }
+
+        if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.menu.reset();
+            self.palette.open();
+            return;
         }
+
+        // keys not used by menu, e.g. plain chars when menu is closed, are
+        // left to the app
+        self.menu.handle_key_event(key);
     }
 }
 
@@ -123,9 +240,19 @@ impl Widget for &mut App {
         use Constraint::*;
         let [top, main] = Layout::vertical([Length(1), Fill(1)]).areas(area);
 
-        Paragraph::new(self.content.as_str())
-            .block(Block::bordered().title("Content").on_black())
-            .render(main, buf);
+        let mut paragraph = Paragraph::new(self.content.as_str()).block(
+            Block::bordered()
+                .title("Content")
+                .title_bottom(self.status.as_str())
+                .on_black(),
+        );
+        if self.light_theme {
+            paragraph = paragraph.black().on_white();
+        }
+        if self.word_wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
+        paragraph.render(main, buf);
 
         "tui-menu"
             .bold()
@@ -134,6 +261,7 @@ impl Widget for &mut App {
             .render(top, buf);
 
         // draw menu last, so it renders on top of other content
-        Menu::new().render(top, buf, &mut self.menu);
+        Menu::new().tooltip(true).render(top, buf, &mut self.menu);
+        CommandPalette::new(&self.menu).render(area, buf, &mut self.palette);
     }
 }
@@ -3,7 +3,7 @@ use ratatui::{
     prelude::*,
     widgets::{Block, Paragraph},
 };
-use tui_menu::{Menu, MenuEvent, MenuItem, MenuState};
+use tui_menu::{menu, Menu, MenuEvent, MenuState};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
@@ -21,39 +21,26 @@ impl App {
     fn new() -> Self {
         Self {
             content: String::new(),
-            menu: MenuState::new(vec![
-                MenuItem::group(
-                    "Group 1",
-                    vec![
-                        MenuItem::group(
-                            "Nested",
-                            vec![MenuItem::group(
-                                "Nested 1",
-                                vec![MenuItem::group(
-                                    "Nested 2",
-                                    vec![
-                                        MenuItem::group(
-                                            "Nested 3",
-                                            vec![MenuItem::group(
-                                                "Nested 4",
-                                                vec![
-                                                    MenuItem::group(
-                                                        "Nested 5",
-                                                        vec![MenuItem::group("Nested 6", vec![])],
-                                                    ),
-                                                    MenuItem::item("Item 5", Action::Exit),
-                                                ],
-                                            )],
-                                        ),
-                                        MenuItem::item("Item 3a", Action::Exit),
+            menu: MenuState::new(menu![
+                "Group 1" => [
+                    "Nested" => [
+                        "Nested 1" => [
+                            "Nested 2" => [
+                                "Nested 3" => [
+                                    "Nested 4" => [
+                                        "Nested 5" => [
+                                            "Nested 6" => [],
+                                        ],
+                                        "Item 5" => Action::Exit,
                                     ],
-                                )],
-                            )],
-                        ),
-                        MenuItem::item("Exit", Action::Exit),
+                                ],
+                                "Item 3a" => Action::Exit,
+                            ],
+                        ],
                     ],
-                ),
-                MenuItem::item("Exit", Action::Exit),
+                    "Exit" => Action::Exit,
+                ],
+                "Exit" => Action::Exit,
             ]),
         }
     }
@@ -76,12 +63,8 @@ impl App {
             }
 
             for e in self.menu.drain_events() {
-                match e {
-                    MenuEvent::Selected(item) => match item {
-                        Action::Exit => {
-                            return Ok(());
-                        }
-                    },
+                if let MenuEvent::Selected(Action::Exit) = e {
+                    return Ok(());
                 }
             }
         }
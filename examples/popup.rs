@@ -0,0 +1,139 @@
+use ratatui::{
+    crossterm::{
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers,
+            MouseButton, MouseEventKind,
+        },
+        execute,
+    },
+    prelude::*,
+    widgets::{Block, Paragraph},
+};
+use tui_menu::{MenuEvent, MenuItem, MenuState, MouseAction, PopupMenu};
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    execute!(std::io::stdout(), EnableMouseCapture)?;
+    let result = ratatui::run(|t| App::new().run(t));
+    execute!(std::io::stdout(), DisableMouseCapture)?;
+    result?;
+    Ok(())
+}
+
+struct App {
+    content: String,
+    /// where the popup opens
+    position: Position,
+    menu: MenuState<Action>,
+}
+
+impl App {
+    fn new() -> Self {
+        Self {
+            content: "right click, or press Shift+F10 to open context menu".into(),
+            position: Position::new(2, 2),
+            menu: MenuState::new(vec![
+                MenuItem::item("&Copy", Action::Copy),
+                MenuItem::item("&Paste", Action::Paste),
+                MenuItem::group(
+                    "&Transform",
+                    vec![
+                        MenuItem::item("&Upper case", Action::UpperCase),
+                        MenuItem::item("&Lower case", Action::LowerCase),
+                    ],
+                ),
+                MenuItem::separator(),
+                MenuItem::item("E&xit", Action::Exit),
+            ]),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Action {
+    Copy,
+    Paste,
+    UpperCase,
+    LowerCase,
+    Exit,
+}
+
+impl App {
+    fn run(mut self, terminal: &mut ratatui::DefaultTerminal) -> std::io::Result<()> {
+        loop {
+            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+
+            if event::poll(std::time::Duration::from_millis(10))? {
+                match event::read()? {
+                    Event::Key(key) => self.on_key_event(key),
+                    Event::Mouse(mouse) => self.on_mouse_event(mouse),
+                    _ => {}
+                }
+            }
+
+            for e in self.menu.drain_events() {
+                match e {
+                    MenuEvent::Selected(Action::Exit) => return Ok(()),
+                    MenuEvent::Selected(Action::UpperCase) => {
+                        self.content = self.content.to_uppercase();
+                    }
+                    MenuEvent::Selected(Action::LowerCase) => {
+                        self.content = self.content.to_lowercase();
+                    }
+                    MenuEvent::Selected(action) => {
+                        self.content = format!("{action:?} not implemented");
+                    }
+                    _ => continue,
+                }
+                self.menu.reset();
+            }
+        }
+    }
+
+    fn on_key_event(&mut self, key: event::KeyEvent) {
+        match key.code {
+            KeyCode::F(10) if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.menu.activate_popup();
+            }
+            KeyCode::Char('h') | KeyCode::Left => self.menu.left(),
+            KeyCode::Char('l') | KeyCode::Right => self.menu.right(),
+            KeyCode::Char('j') | KeyCode::Down => self.menu.down(),
+            KeyCode::Char('k') | KeyCode::Up => self.menu.up(),
+            KeyCode::Esc => self.menu.reset(),
+            KeyCode::Enter => self.menu.select(),
+            KeyCode::Char(c) => {
+                self.menu.handle_char(c);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_mouse_event(&mut self, mouse: event::MouseEvent) {
+        let action = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Right) => {
+                self.menu.reset();
+                self.position = Position::new(mouse.column, mouse.row);
+                self.menu.activate_popup();
+                return;
+            }
+            MouseEventKind::Down(_) => MouseAction::Click,
+            MouseEventKind::Moved => MouseAction::Hover,
+            MouseEventKind::ScrollUp => MouseAction::ScrollUp,
+            MouseEventKind::ScrollDown => MouseAction::ScrollDown,
+            _ => return,
+        };
+        self.menu.handle_mouse(mouse.column, mouse.row, action);
+    }
+}
+
+impl Widget for &mut App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.content.as_str())
+            .block(Block::bordered().title("Content").on_black())
+            .render(area, buf);
+
+        // draw popup last, so it renders on top of other content
+        PopupMenu::new(self.position).render(area, buf, &mut self.menu);
+    }
+}
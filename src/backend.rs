@@ -0,0 +1,73 @@
+//! Backend neutral [MenuInput], converted from input events of terminal
+//! backends behind their cargo features
+
+use crate::{EventOutcome, KeyChord, MenuState, MouseAction};
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(all(feature = "termion", unix))]
+mod termion;
+#[cfg(feature = "termwiz")]
+mod termwiz;
+
+#[cfg(feature = "termwiz")]
+pub use termwiz::TermwizInput;
+
+/// Input handled by [MenuState::handle_input]. Events of crossterm and
+/// termion convert to it with `TryFrom`, behind the feature of same name,
+/// failing with the event for input menu doesn't use. Termion is unix only. Termwiz events are
+/// converted by `TermwizInput` with `termwiz` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuInput {
+    /// key press
+    Key(KeyChord),
+    /// mouse at terminal position, 0 based
+    Mouse {
+        column: u16,
+        row: u16,
+        action: MouseAction,
+    },
+}
+
+impl From<KeyChord> for MenuInput {
+    fn from(chord: KeyChord) -> Self {
+        MenuInput::Key(chord)
+    }
+}
+
+impl<T: Clone> MenuState<T> {
+    /// handle key with [MenuState::handle_key] and mouse with
+    /// [MenuState::handle_mouse]
+    pub fn handle_input(&mut self, input: MenuInput) -> EventOutcome {
+        match input {
+            MenuInput::Key(chord) => self.handle_key(chord),
+            MenuInput::Mouse {
+                column,
+                row,
+                action,
+            } => self.handle_mouse(column, row, action).into(),
+        }
+    }
+
+    /// handle event of any backend which converts to [MenuInput], e.g.
+    /// `&crossterm::event::Event`. Other events are
+    /// [EventOutcome::Ignored].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{EventOutcome, Key, KeyChord, MenuInput, MenuItem, MenuState};
+    ///
+    /// let mut state = MenuState::new(vec![MenuItem::item("&Quit", "quit")]);
+    ///
+    /// let input = MenuInput::Key(KeyChord::new(Key::F(10)));
+    /// assert_eq!(state.handle_event(input), EventOutcome::Handled);
+    /// assert!(state.is_active());
+    /// ```
+    pub fn handle_event<E: TryInto<MenuInput>>(&mut self, event: E) -> EventOutcome {
+        match event.try_into() {
+            Ok(input) => self.handle_input(input),
+            Err(_) => EventOutcome::Ignored,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{EventOutcome, Key, KeyChord, MenuInput, MenuState, MouseAction};
+use ::crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
+
+/// Fails with the event itself for keys without [Key], e.g. media keys
+impl TryFrom<KeyEvent> for KeyChord {
+    type Error = KeyEvent;
+
+    fn try_from(event: KeyEvent) -> Result<Self, Self::Error> {
+        let key = match event.code {
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Esc => Key::Esc,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Tab => Key::Tab,
+            KeyCode::BackTab => Key::BackTab,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Home => Key::Home,
+            KeyCode::End => Key::End,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            KeyCode::Insert => Key::Insert,
+            KeyCode::Delete => Key::Delete,
+            KeyCode::F(n) => Key::F(n),
+            _ => return Err(event),
+        };
+
+        let mut chord = KeyChord::new(key);
+        if event.modifiers.contains(KeyModifiers::CONTROL) {
+            chord = chord.ctrl();
+        }
+        if event.modifiers.contains(KeyModifiers::ALT) {
+            chord = chord.alt();
+        }
+        if event.modifiers.contains(KeyModifiers::SHIFT) {
+            chord = chord.shift();
+        }
+        Ok(chord)
+    }
+}
+
+/// Key press and mouse events, key release and button release fail
+impl<'a> TryFrom<&'a Event> for MenuInput {
+    type Error = &'a Event;
+
+    fn try_from(event: &'a Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Key(key) if key.kind != KeyEventKind::Release => KeyChord::try_from(*key)
+                .map(MenuInput::Key)
+                .map_err(|_| event),
+            Event::Mouse(MouseEvent {
+                kind, column, row, ..
+            }) => {
+                let action = match kind {
+                    MouseEventKind::Down(_) => MouseAction::Click,
+                    MouseEventKind::Moved | MouseEventKind::Drag(_) => MouseAction::Hover,
+                    MouseEventKind::ScrollUp => MouseAction::ScrollUp,
+                    MouseEventKind::ScrollDown => MouseAction::ScrollDown,
+                    _ => return Err(event),
+                };
+                Ok(MenuInput::Mouse {
+                    column: *column,
+                    row: *row,
+                    action,
+                })
+            }
+            _ => Err(event),
+        }
+    }
+}
+
+impl<T: Clone> MenuState<T> {
+    /// handle crossterm key event with [MenuState::handle_key], key release
+    /// and keys without [Key] are ignored. Use [MenuState::handle_event]
+    /// with `&Event` for mouse too
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// use tui_menu::{EventOutcome, MenuItem, MenuKeymap, MenuState};
+    ///
+    /// let mut state = MenuState::new(vec![MenuItem::item("&Quit", "quit")])
+    ///     .keymap(MenuKeymap::vim());
+    ///
+    /// let event = KeyEvent::new(KeyCode::F(10), KeyModifiers::NONE);
+    /// assert_eq!(state.handle_key_event(event), EventOutcome::Handled);
+    /// assert!(state.is_active());
+    /// ```
+    pub fn handle_key_event(&mut self, event: KeyEvent) -> EventOutcome {
+        self.handle_event(&Event::Key(event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Key, KeyChord, MenuInput, MouseAction};
+    use ::crossterm::event::{
+        Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton,
+        MouseEvent, MouseEventKind,
+    };
+
+    #[test]
+    fn test_crossterm_key_event() {
+        let chord = |code, modifiers| KeyChord::try_from(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            chord(
+                KeyCode::Char('N'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ),
+            Ok(KeyChord::new(Key::Char('n')).ctrl().shift())
+        );
+        assert_eq!(
+            chord(KeyCode::Char('J'), KeyModifiers::SHIFT),
+            Ok(KeyChord::new(Key::Char('J')))
+        );
+        assert_eq!(
+            chord(KeyCode::F(10), KeyModifiers::SHIFT),
+            Ok(KeyChord::new(Key::F(10)).shift())
+        );
+        assert!(chord(KeyCode::CapsLock, KeyModifiers::NONE).is_err());
+    }
+
+    #[test]
+    fn test_crossterm_event() {
+        let mouse = |kind| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column: 3,
+                row: 1,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        assert_eq!(
+            MenuInput::try_from(&mouse(MouseEventKind::Down(MouseButton::Left))),
+            Ok(MenuInput::Mouse {
+                column: 3,
+                row: 1,
+                action: MouseAction::Click
+            })
+        );
+        let release = mouse(MouseEventKind::Up(MouseButton::Left));
+        assert_eq!(MenuInput::try_from(&release), Err(&release));
+
+        let key_release = Event::Key(KeyEvent {
+            code: KeyCode::Enter,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Release,
+            state: KeyEventState::NONE,
+        });
+        assert!(MenuInput::try_from(&key_release).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Key, KeyChord, MenuInput, MouseAction};
+use ::termion::event::{Event, Key as TermionKey, MouseButton, MouseEvent};
+
+/// Fails with the key itself for keys without [Key], e.g. null byte
+impl TryFrom<TermionKey> for KeyChord {
+    type Error = TermionKey;
+
+    fn try_from(key: TermionKey) -> Result<Self, Self::Error> {
+        let chord = |key| KeyChord::new(key);
+        Ok(match key {
+            TermionKey::Char('\n') => chord(Key::Enter),
+            TermionKey::Char('\t') => chord(Key::Tab),
+            TermionKey::Char(c) => chord(Key::Char(c)),
+            TermionKey::Alt(c) => chord(Key::Char(c)).alt(),
+            TermionKey::Ctrl(c) => chord(Key::Char(c)).ctrl(),
+            TermionKey::Backspace => chord(Key::Backspace),
+            TermionKey::Esc => chord(Key::Esc),
+            TermionKey::BackTab => chord(Key::BackTab),
+            TermionKey::Up => chord(Key::Up),
+            TermionKey::ShiftUp => chord(Key::Up).shift(),
+            TermionKey::AltUp => chord(Key::Up).alt(),
+            TermionKey::CtrlUp => chord(Key::Up).ctrl(),
+            TermionKey::Down => chord(Key::Down),
+            TermionKey::ShiftDown => chord(Key::Down).shift(),
+            TermionKey::AltDown => chord(Key::Down).alt(),
+            TermionKey::CtrlDown => chord(Key::Down).ctrl(),
+            TermionKey::Left => chord(Key::Left),
+            TermionKey::ShiftLeft => chord(Key::Left).shift(),
+            TermionKey::AltLeft => chord(Key::Left).alt(),
+            TermionKey::CtrlLeft => chord(Key::Left).ctrl(),
+            TermionKey::Right => chord(Key::Right),
+            TermionKey::ShiftRight => chord(Key::Right).shift(),
+            TermionKey::AltRight => chord(Key::Right).alt(),
+            TermionKey::CtrlRight => chord(Key::Right).ctrl(),
+            TermionKey::Home => chord(Key::Home),
+            TermionKey::CtrlHome => chord(Key::Home).ctrl(),
+            TermionKey::End => chord(Key::End),
+            TermionKey::CtrlEnd => chord(Key::End).ctrl(),
+            TermionKey::PageUp => chord(Key::PageUp),
+            TermionKey::PageDown => chord(Key::PageDown),
+            TermionKey::Insert => chord(Key::Insert),
+            TermionKey::Delete => chord(Key::Delete),
+            TermionKey::F(n) => chord(Key::F(n)),
+            _ => return Err(key),
+        })
+    }
+}
+
+/// Key and mouse events. Termion reports no pointer move without button,
+/// so hover is only tracked while dragging
+impl<'a> TryFrom<&'a Event> for MenuInput {
+    type Error = &'a Event;
+
+    fn try_from(event: &'a Event) -> Result<Self, Self::Error> {
+        let (action, column, row) = match event {
+            Event::Key(key) => {
+                return KeyChord::try_from(*key)
+                    .map(MenuInput::Key)
+                    .map_err(|_| event);
+            }
+            Event::Mouse(MouseEvent::Press(button, x, y)) => {
+                let action = match button {
+                    MouseButton::Left | MouseButton::Right | MouseButton::Middle => {
+                        MouseAction::Click
+                    }
+                    MouseButton::WheelUp => MouseAction::ScrollUp,
+                    MouseButton::WheelDown => MouseAction::ScrollDown,
+                    _ => return Err(event),
+                };
+                (action, x, y)
+            }
+            Event::Mouse(MouseEvent::Hold(x, y)) => (MouseAction::Hover, x, y),
+            _ => return Err(event),
+        };
+        // termion is 1 based
+        Ok(MenuInput::Mouse {
+            column: column.saturating_sub(1),
+            row: row.saturating_sub(1),
+            action,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Key, KeyChord, MenuInput, MouseAction};
+    use ::termion::event::{Event, Key as TermionKey, MouseButton, MouseEvent};
+
+    #[test]
+    fn test_termion_event() {
+        let input = |event| MenuInput::try_from(&event).ok();
+        assert_eq!(
+            input(Event::Key(TermionKey::Ctrl('n'))),
+            Some(KeyChord::new(Key::Char('n')).ctrl().into())
+        );
+        assert_eq!(
+            input(Event::Key(TermionKey::Char('\n'))),
+            Some(KeyChord::new(Key::Enter).into())
+        );
+        assert_eq!(
+            input(Event::Key(TermionKey::ShiftLeft)),
+            Some(KeyChord::new(Key::Left).shift().into())
+        );
+        assert_eq!(
+            input(Event::Mouse(MouseEvent::Press(MouseButton::Left, 1, 1))),
+            Some(MenuInput::Mouse {
+                column: 0,
+                row: 0,
+                action: MouseAction::Click
+            })
+        );
+        assert_eq!(input(Event::Mouse(MouseEvent::Release(1, 1))), None);
+        assert_eq!(input(Event::Key(TermionKey::Null)), None);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{Key, KeyChord, MenuInput, MouseAction};
+use ::termwiz::input::{InputEvent, KeyCode, KeyEvent, Modifiers, MouseButtons, MouseEvent};
+
+/// Fails with the event itself for keys without [Key], e.g. media keys
+impl TryFrom<KeyEvent> for KeyChord {
+    type Error = KeyEvent;
+
+    fn try_from(event: KeyEvent) -> Result<Self, Self::Error> {
+        let key = match event.key {
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Escape => Key::Esc,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Tab => Key::Tab,
+            KeyCode::UpArrow | KeyCode::ApplicationUpArrow => Key::Up,
+            KeyCode::DownArrow | KeyCode::ApplicationDownArrow => Key::Down,
+            KeyCode::LeftArrow | KeyCode::ApplicationLeftArrow => Key::Left,
+            KeyCode::RightArrow | KeyCode::ApplicationRightArrow => Key::Right,
+            KeyCode::Home | KeyCode::KeyPadHome => Key::Home,
+            KeyCode::End | KeyCode::KeyPadEnd => Key::End,
+            KeyCode::PageUp | KeyCode::KeyPadPageUp => Key::PageUp,
+            KeyCode::PageDown | KeyCode::KeyPadPageDown => Key::PageDown,
+            KeyCode::Insert => Key::Insert,
+            KeyCode::Delete => Key::Delete,
+            KeyCode::Function(n) => Key::F(n),
+            _ => return Err(event),
+        };
+
+        let mut chord = KeyChord::new(key);
+        if event.modifiers.contains(Modifiers::CTRL) {
+            chord = chord.ctrl();
+        }
+        if event.modifiers.contains(Modifiers::ALT) {
+            chord = chord.alt();
+        }
+        if event.modifiers.contains(Modifiers::SHIFT) {
+            chord = chord.shift();
+        }
+        Ok(chord)
+    }
+}
+
+const BUTTONS: MouseButtons = MouseButtons::LEFT
+    .union(MouseButtons::RIGHT)
+    .union(MouseButtons::MIDDLE);
+
+/// Converts termwiz events to [MenuInput]. Termwiz reports the buttons
+/// held on every mouse move instead of presses, so it keeps the last held
+/// buttons to emit [MouseAction::Click] only when one is pressed. Moves,
+/// with or without button held, are [MouseAction::Hover].
+///
+/// # Example
+///
+/// ```
+/// use termwiz::input::{InputEvent, Modifiers, MouseButtons, MouseEvent};
+/// use tui_menu::{MenuInput, MouseAction, TermwizInput};
+///
+/// let mut input = TermwizInput::new();
+/// let mouse = |x, mouse_buttons| {
+///     InputEvent::Mouse(MouseEvent { x, y: 1, mouse_buttons, modifiers: Modifiers::NONE })
+/// };
+///
+/// let press = input.convert(&mouse(3, MouseButtons::LEFT));
+/// assert!(matches!(press, Some(MenuInput::Mouse { action: MouseAction::Click, .. })));
+/// // dragging is not another click
+/// let drag = input.convert(&mouse(4, MouseButtons::LEFT));
+/// assert!(matches!(drag, Some(MenuInput::Mouse { action: MouseAction::Hover, .. })));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TermwizInput {
+    /// buttons held in last mouse event
+    buttons: MouseButtons,
+}
+
+impl TermwizInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// convert key and mouse events, others and button release are None.
+    /// Pass it to [MenuState::handle_input](crate::MenuState::handle_input)
+    pub fn convert(&mut self, event: &InputEvent) -> Option<MenuInput> {
+        match event {
+            InputEvent::Key(key) => KeyChord::try_from(key.clone()).ok().map(MenuInput::Key),
+            InputEvent::Mouse(MouseEvent {
+                x,
+                y,
+                mouse_buttons,
+                ..
+            }) => {
+                let action = if mouse_buttons.contains(MouseButtons::VERT_WHEEL) {
+                    // wheel is reported alone, held buttons are unknown
+                    if mouse_buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+                        MouseAction::ScrollUp
+                    } else {
+                        MouseAction::ScrollDown
+                    }
+                } else if mouse_buttons.contains(MouseButtons::HORZ_WHEEL) {
+                    return None;
+                } else {
+                    let held = mouse_buttons.clone().intersection(BUTTONS);
+                    let last = std::mem::replace(&mut self.buttons, held.clone());
+                    if !held.clone().difference(last.clone()).is_empty() {
+                        MouseAction::Click
+                    } else if held.is_empty() && !last.is_empty() {
+                        return None;
+                    } else {
+                        MouseAction::Hover
+                    }
+                };
+                Some(MenuInput::Mouse {
+                    column: zero_based(*x),
+                    row: zero_based(*y),
+                    action,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// mouse report of terminal is 1 based, windows console is 0 based
+fn zero_based(position: u16) -> u16 {
+    if cfg!(windows) {
+        position
+    } else {
+        position.saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Key, KeyChord, MenuInput, MouseAction, TermwizInput};
+    use ::termwiz::input::{InputEvent, KeyCode, KeyEvent, Modifiers, MouseButtons, MouseEvent};
+
+    #[test]
+    fn test_termwiz_event() {
+        let mut termwiz = TermwizInput::new();
+        let mut input = |event| termwiz.convert(&event);
+        let key = |key, modifiers| InputEvent::Key(KeyEvent { key, modifiers });
+        assert_eq!(
+            input(key(KeyCode::Char('N'), Modifiers::CTRL | Modifiers::SHIFT)),
+            Some(KeyChord::new(Key::Char('n')).ctrl().shift().into())
+        );
+        assert_eq!(
+            input(key(KeyCode::ApplicationUpArrow, Modifiers::NONE)),
+            Some(KeyChord::new(Key::Up).into())
+        );
+        assert_eq!(input(key(KeyCode::VolumeUp, Modifiers::NONE)), None);
+
+        let mouse = |mouse_buttons| {
+            InputEvent::Mouse(MouseEvent {
+                x: 4,
+                y: 2,
+                mouse_buttons,
+                modifiers: Modifiers::NONE,
+            })
+        };
+        let (column, row) = if cfg!(windows) { (4, 2) } else { (3, 1) };
+        let action = |action| {
+            Some(MenuInput::Mouse {
+                column,
+                row,
+                action,
+            })
+        };
+        assert_eq!(
+            input(mouse(
+                MouseButtons::VERT_WHEEL | MouseButtons::WHEEL_POSITIVE
+            )),
+            action(MouseAction::ScrollUp)
+        );
+        assert_eq!(input(mouse(MouseButtons::NONE)), action(MouseAction::Hover));
+        assert_eq!(input(mouse(MouseButtons::HORZ_WHEEL)), None);
+
+        // press, drag, release, and press again
+        assert_eq!(input(mouse(MouseButtons::LEFT)), action(MouseAction::Click));
+        assert_eq!(input(mouse(MouseButtons::LEFT)), action(MouseAction::Hover));
+        assert_eq!(
+            input(mouse(MouseButtons::LEFT | MouseButtons::RIGHT)),
+            action(MouseAction::Click)
+        );
+        assert_eq!(input(mouse(MouseButtons::NONE)), None);
+        assert_eq!(input(mouse(MouseButtons::NONE)), action(MouseAction::Hover));
+        assert_eq!(input(mouse(MouseButtons::LEFT)), action(MouseAction::Click));
+    }
+}
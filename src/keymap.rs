@@ -0,0 +1,633 @@
+//! Key bindings of menu navigation, independent of terminal backend
+
+use crate::MenuState;
+use std::{collections::HashMap, fmt, str::FromStr, time::Instant};
+
+/// Key without modifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Backspace,
+    Tab,
+    BackTab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Insert,
+    Delete,
+    /// function key, e.g. `F(10)` for F10
+    F(u8),
+}
+
+/// Key with modifiers, e.g. Ctrl+N. It is written like shortcut hints,
+/// "Ctrl+N", "Alt+F" or "Shift+F10", and parsed from the same form.
+///
+/// Without Ctrl or Alt, Shift of char is carried by its case, e.g.
+/// "Shift+J" is "J". With them, char is case insensitive and Shift is
+/// kept, so "Ctrl+Shift+S" and "Ctrl+S" are different chords.
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{Key, KeyChord};
+///
+/// let chord: KeyChord = "ctrl+n".parse().unwrap();
+/// assert_eq!(chord, KeyChord::new(Key::Char('n')).ctrl());
+/// assert_eq!(chord.to_string(), "Ctrl+N");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    key: Key,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+}
+
+impl KeyChord {
+    /// key without modifiers
+    pub const fn new(key: Key) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    /// with Ctrl held
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self.normalize()
+    }
+
+    /// with Alt held
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self.normalize()
+    }
+
+    /// with Shift held, it makes char upper case without Ctrl or Alt
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self.normalize()
+    }
+
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    pub fn has_ctrl(&self) -> bool {
+        self.ctrl
+    }
+
+    pub fn has_alt(&self) -> bool {
+        self.alt
+    }
+
+    pub fn has_shift(&self) -> bool {
+        self.shift
+    }
+
+    /// char typed without Ctrl or Alt
+    fn typed_char(&self) -> Option<char> {
+        match self.key {
+            Key::Char(c) if !self.ctrl && !self.alt => Some(c),
+            _ => None,
+        }
+    }
+
+    /// without Ctrl or Alt, Shift is the case of char. With them, char is
+    /// lower case and upper case means Shift. Shift of chars without case,
+    /// e.g. '?', is part of the char
+    fn normalize(mut self) -> Self {
+        let Key::Char(c) = self.key else {
+            return self;
+        };
+        let has_case = c.is_lowercase() || c.is_uppercase();
+        if self.ctrl || self.alt {
+            self.shift = has_case && (self.shift || c.is_uppercase());
+            self.key = Key::Char(crate::fold_case(c));
+        } else {
+            if self.shift && has_case {
+                self.key = Key::Char(c.to_uppercase().next().unwrap_or(c));
+            }
+            self.shift = false;
+        }
+        self
+    }
+}
+
+impl From<Key> for KeyChord {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (held, name) in [
+            (self.ctrl, "Ctrl+"),
+            (self.alt, "Alt+"),
+            (self.shift, "Shift+"),
+        ] {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+        match self.key {
+            Key::Char(' ') => f.write_str("Space"),
+            Key::Char(c) if self.ctrl || self.alt => write!(f, "{}", c.to_uppercase()),
+            Key::Char(c) => write!(f, "{c}"),
+            Key::F(n) => write!(f, "F{n}"),
+            key => write!(f, "{key:?}"),
+        }
+    }
+}
+
+/// Error of parsing [KeyChord] or [MenuAction]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyError(String);
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown key or menu action `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseKeyError {}
+
+impl FromStr for KeyChord {
+    type Err = ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseKeyError(s.to_string());
+        // "+" and "Ctrl++" are the '+' key
+        let (modifiers, key) = match s.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        let key = match key.to_ascii_lowercase().as_str() {
+            "enter" | "return" => Key::Enter,
+            "esc" | "escape" => Key::Esc,
+            "backspace" => Key::Backspace,
+            "tab" => Key::Tab,
+            "backtab" => Key::BackTab,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" => Key::PageUp,
+            "pagedown" => Key::PageDown,
+            "insert" | "ins" => Key::Insert,
+            "delete" | "del" => Key::Delete,
+            "space" => Key::Char(' '),
+            lower => match lower.strip_prefix('f').map(str::parse) {
+                Some(Ok(n)) => Key::F(n),
+                _ => {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Key::Char(c),
+                        _ => return Err(err()),
+                    }
+                }
+            },
+        };
+
+        let (mut ctrl, mut alt, mut shift) = (false, false, false);
+        for modifier in modifiers.split('+').filter(|m| !m.is_empty()) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ctrl = true,
+                "alt" => alt = true,
+                "shift" => shift = true,
+                _ => return Err(err()),
+            }
+        }
+
+        let mut chord = KeyChord::new(key);
+        // "Ctrl+S" is written upper case, but doesn't mean Shift
+        if let (Key::Char(c), true) = (key, ctrl || alt) {
+            chord.key = Key::Char(crate::fold_case(c));
+        }
+        if ctrl {
+            chord = chord.ctrl();
+        }
+        if alt {
+            chord = chord.alt();
+        }
+        if shift {
+            chord = chord.shift();
+        }
+        Ok(chord)
+    }
+}
+
+/// Menu navigation a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MenuAction {
+    /// [MenuState::up]
+    Up,
+    /// [MenuState::down]
+    Down,
+    /// [MenuState::left]
+    Left,
+    /// [MenuState::right]
+    Right,
+    /// [MenuState::select]
+    Select,
+    /// [MenuState::reset]
+    Cancel,
+    /// [MenuState::activate] the menu, or close it if active, e.g. F10
+    Activate,
+}
+
+impl MenuAction {
+    const NAMES: [(&'static str, MenuAction); 7] = [
+        ("up", MenuAction::Up),
+        ("down", MenuAction::Down),
+        ("left", MenuAction::Left),
+        ("right", MenuAction::Right),
+        ("select", MenuAction::Select),
+        ("cancel", MenuAction::Cancel),
+        ("activate", MenuAction::Activate),
+    ];
+}
+
+impl fmt::Display for MenuAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, _) = Self::NAMES
+            .iter()
+            .find(|(_, action)| action == self)
+            .expect("every action has a name");
+        f.write_str(name)
+    }
+}
+
+impl FromStr for MenuAction {
+    type Err = ParseKeyError;
+
+    /// parse from lower case name, e.g. "select"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|(_, action)| *action)
+            .ok_or_else(|| ParseKeyError(s.to_string()))
+    }
+}
+
+/// Maps key chords to [MenuAction], used by [MenuState::handle_key].
+/// Default is [MenuKeymap::arrows].
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{Key, KeyChord, MenuAction, MenuKeymap};
+///
+/// // e.g. bindings from user configuration
+/// let config = [("Ctrl+K", "up"), ("Ctrl+J", "down")];
+///
+/// let mut keymap = MenuKeymap::vim();
+/// for (key, action) in config {
+///     keymap = keymap.bind(key.parse().unwrap(), action.parse().unwrap());
+/// }
+///
+/// assert_eq!(
+///     keymap.action(&KeyChord::new(Key::Char('k')).ctrl()),
+///     Some(MenuAction::Up),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuKeymap {
+    bindings: HashMap<KeyChord, MenuAction>,
+}
+
+impl MenuKeymap {
+    /// keymap without any binding
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// arrow keys, Enter to select, Esc to cancel and F10 to activate
+    pub fn arrows() -> Self {
+        Self::new()
+            .bind(Key::Up.into(), MenuAction::Up)
+            .bind(Key::Down.into(), MenuAction::Down)
+            .bind(Key::Left.into(), MenuAction::Left)
+            .bind(Key::Right.into(), MenuAction::Right)
+            .bind(Key::Enter.into(), MenuAction::Select)
+            .bind(Key::Esc.into(), MenuAction::Cancel)
+            .bind(Key::F(10).into(), MenuAction::Activate)
+    }
+
+    /// [MenuKeymap::arrows] and h, j, k, l.
+    /// NOTE: these letters are not mnemonics or type-ahead then
+    pub fn vim() -> Self {
+        Self::arrows()
+            .bind(Key::Char('h').into(), MenuAction::Left)
+            .bind(Key::Char('j').into(), MenuAction::Down)
+            .bind(Key::Char('k').into(), MenuAction::Up)
+            .bind(Key::Char('l').into(), MenuAction::Right)
+    }
+
+    /// [MenuKeymap::arrows] and Ctrl+P, Ctrl+N, Ctrl+B, Ctrl+F, Ctrl+G to
+    /// cancel. They are shortcuts again when menu is not active.
+    pub fn emacs() -> Self {
+        Self::arrows()
+            .bind(KeyChord::new(Key::Char('p')).ctrl(), MenuAction::Up)
+            .bind(KeyChord::new(Key::Char('n')).ctrl(), MenuAction::Down)
+            .bind(KeyChord::new(Key::Char('b')).ctrl(), MenuAction::Left)
+            .bind(KeyChord::new(Key::Char('f')).ctrl(), MenuAction::Right)
+            .bind(KeyChord::new(Key::Char('g')).ctrl(), MenuAction::Cancel)
+    }
+
+    /// bind `chord` to `action`, replacing its previous binding
+    pub fn bind(mut self, chord: KeyChord, action: MenuAction) -> Self {
+        self.bindings.insert(chord, action);
+        self
+    }
+
+    /// remove binding of `chord`
+    pub fn unbind(mut self, chord: KeyChord) -> Self {
+        self.bindings.remove(&chord);
+        self
+    }
+
+    /// action bound to `chord`
+    pub fn action(&self, chord: &KeyChord) -> Option<MenuAction> {
+        self.bindings.get(chord).copied()
+    }
+
+    /// all bindings, in no particular order
+    pub fn bindings(&self) -> impl Iterator<Item = (KeyChord, MenuAction)> + '_ {
+        self.bindings
+            .iter()
+            .map(|(chord, action)| (*chord, *action))
+    }
+}
+
+impl FromIterator<(KeyChord, MenuAction)> for MenuKeymap {
+    fn from_iter<I: IntoIterator<Item = (KeyChord, MenuAction)>>(iter: I) -> Self {
+        Self {
+            bindings: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Serialized as a map from chord to action, e.g. `"Ctrl+N" = "down"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for MenuKeymap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.bindings
+                .iter()
+                .map(|(chord, action)| (chord.to_string(), action.to_string())),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MenuKeymap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let bindings = HashMap::<String, String>::deserialize(deserializer)?;
+        bindings
+            .iter()
+            .map(|(chord, action)| Ok((chord.parse()?, action.parse()?)))
+            .collect::<Result<_, ParseKeyError>>()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Whether input is consumed by the menu, [EventOutcome::Ignored] input
+/// should be handled by the rest of the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventOutcome {
+    Handled,
+    Ignored,
+}
+
+impl EventOutcome {
+    pub fn is_handled(&self) -> bool {
+        *self == EventOutcome::Handled
+    }
+}
+
+impl From<bool> for EventOutcome {
+    fn from(handled: bool) -> Self {
+        if handled {
+            EventOutcome::Handled
+        } else {
+            EventOutcome::Ignored
+        }
+    }
+}
+
+impl<T: Clone> MenuState<T> {
+    /// update keymap used by [MenuState::handle_key], default is
+    /// [MenuKeymap::arrows]
+    pub fn keymap(mut self, keymap: MenuKeymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// handle key press with keymap, mnemonics, shortcuts and type-ahead.
+    ///
+    /// When menu is not active, only [MenuAction::Activate], Alt+char
+    /// mnemonics and shortcuts are handled, so other keys are left to the
+    /// app.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{EventOutcome, Key, KeyChord, MenuEvent, MenuItem, MenuState};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("&File", vec![
+    ///         MenuItem::item("&New", "label_new").shortcut("Ctrl+N"),
+    ///     ]),
+    /// ]);
+    ///
+    /// // arrow keys belong to the app while menu is closed
+    /// assert_eq!(state.handle_key(Key::Down.into()), EventOutcome::Ignored);
+    ///
+    /// let ctrl_n = KeyChord::new(Key::Char('n')).ctrl();
+    /// assert_eq!(state.handle_key(ctrl_n), EventOutcome::Handled);
+    /// assert!(matches!(state.drain_events().next(), Some(MenuEvent::Selected("label_new"))));
+    /// ```
+    pub fn handle_key(&mut self, chord: KeyChord) -> EventOutcome {
+        let action = self.keymap.action(&chord);
+
+        if !self.is_active() {
+            if action == Some(MenuAction::Activate) {
+                self.activate();
+                return EventOutcome::Handled;
+            }
+        } else if let Some(action) = action {
+            match action {
+                MenuAction::Up => self.up(),
+                MenuAction::Down => self.down(),
+                MenuAction::Left => self.left(),
+                MenuAction::Right => self.right(),
+                MenuAction::Select => self.select(),
+                MenuAction::Cancel | MenuAction::Activate => self.reset(),
+            }
+            return EventOutcome::Handled;
+        }
+
+        if let (Key::Char(c), true, false) = (chord.key, chord.alt, chord.ctrl) {
+            if self.handle_alt_char(c) {
+                return EventOutcome::Handled;
+            }
+        }
+        if chord.typed_char().is_none() && self.dispatch_chord(&chord) {
+            return EventOutcome::Handled;
+        }
+
+        match chord.typed_char() {
+            Some(c) if self.is_active() => {
+                (self.handle_char(c) || self.type_ahead(c, Instant::now())).into()
+            }
+            _ => EventOutcome::Ignored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        EventOutcome, Key, KeyChord, MenuAction, MenuEvent, MenuItem, MenuKeymap, MenuState,
+    };
+
+    #[test]
+    fn test_key_chord() {
+        let parse = |s: &str| s.parse::<KeyChord>().unwrap();
+        assert_eq!(parse("Ctrl+N"), KeyChord::new(Key::Char('n')).ctrl());
+        assert_eq!(parse("shift+f10"), KeyChord::new(Key::F(10)).shift());
+        assert_eq!(parse("Shift+J"), KeyChord::new(Key::Char('J')));
+        assert_eq!(parse("shift+j"), KeyChord::new(Key::Char('j')).shift());
+        // Shift is kept with Ctrl, in any order
+        let ctrl_shift_s = KeyChord::new(Key::Char('s')).ctrl().shift();
+        assert_eq!(parse("Ctrl+Shift+S"), ctrl_shift_s);
+        assert_eq!(parse("Shift+Ctrl+s"), ctrl_shift_s);
+        assert_eq!(KeyChord::new(Key::Char('S')).ctrl(), ctrl_shift_s);
+        assert_ne!(parse("Ctrl+S"), ctrl_shift_s);
+        assert_eq!(ctrl_shift_s.to_string(), "Ctrl+Shift+S");
+        assert_eq!(parse("Ctrl+Shift+?"), parse("Ctrl+?"));
+        assert_eq!(parse("Ctrl++"), KeyChord::new(Key::Char('+')).ctrl());
+        assert_eq!(parse("+"), KeyChord::new(Key::Char('+')));
+        assert_eq!(parse("Ctrl+Alt+Del").to_string(), "Ctrl+Alt+Delete");
+        assert_eq!(parse("space").to_string(), "Space");
+        assert!("Hyper+X".parse::<KeyChord>().is_err());
+        assert!("Ctrl+Nope".parse::<KeyChord>().is_err());
+        assert_eq!("Select".parse(), Ok(MenuAction::Select));
+        assert_eq!(MenuAction::Cancel.to_string(), "cancel");
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut state = MenuState::new(vec![
+            MenuItem::group(
+                "&File",
+                vec![
+                    MenuItem::item("&New", 1).shortcut("Ctrl+N"),
+                    MenuItem::item("Open", 2).shortcut("F3"),
+                ],
+            ),
+            MenuItem::group(
+                "&View",
+                vec![
+                    MenuItem::item("Zoom", 3),
+                    MenuItem::item("Save", 4).shortcut("Ctrl+S"),
+                    MenuItem::item("Save as", 5).shortcut("Ctrl+Shift+S"),
+                    MenuItem::item("Delete", 6).shortcut("Ctrl+Del"),
+                ],
+            ),
+        ])
+        .keymap(MenuKeymap::emacs());
+        let key = |c| KeyChord::new(Key::Char(c));
+
+        assert_eq!(state.handle_key(key('j')), EventOutcome::Ignored);
+        assert!(state.handle_key(Key::F(3).into()).is_handled());
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(2))
+        ));
+
+        // Ctrl+N navigates when menu is active
+        assert!(state.handle_key(key('f').alt()).is_handled());
+        assert_eq!(state.highlight().unwrap().data, Some(1));
+        assert!(state.handle_key(key('n').ctrl()).is_handled());
+        assert_eq!(state.highlight().unwrap().data, Some(2));
+        assert!(state.handle_key(key('g').ctrl()).is_handled());
+        assert!(!state.is_active());
+        assert!(state.handle_key(key('n').ctrl()).is_handled());
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+
+        // type-ahead, then unbound key is left to app
+        assert!(state.handle_key(Key::F(10).into()).is_handled());
+        assert!(state.handle_key(Key::Down.into()).is_handled());
+        assert!(state.handle_key(key('o')).is_handled());
+        assert_eq!(state.highlight().unwrap().data, Some(2));
+        assert_eq!(state.handle_key(Key::Tab.into()), EventOutcome::Ignored);
+        assert!(state.handle_key(Key::F(10).into()).is_handled());
+        assert!(!state.is_active());
+
+        // shortcuts are matched as chords
+        assert!(state.handle_key(key('S').ctrl()).is_handled());
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(5))
+        ));
+        assert!(state.handle_key(key('s').ctrl()).is_handled());
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(4))
+        ));
+        assert!(state
+            .handle_key(KeyChord::new(Key::Delete).ctrl())
+            .is_handled());
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(6))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_keymap_serde() {
+        let keymap: MenuKeymap = toml::from_str(
+            r#"
+            "Ctrl+N" = "down"
+            "F10" = "activate"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            keymap,
+            MenuKeymap::new()
+                .bind(KeyChord::new(Key::Char('n')).ctrl(), MenuAction::Down)
+                .bind(Key::F(10).into(), MenuAction::Activate)
+        );
+        let text = toml::to_string(&keymap).unwrap();
+        assert_eq!(toml::from_str::<MenuKeymap>(&text).unwrap(), keymap);
+
+        let err = toml::from_str::<MenuKeymap>(r#""Ctrl+N" = "jump""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unknown key or menu action `jump`"));
+    }
+}
@@ -5,7 +5,7 @@ The first holds the configuration, and never changes.
 The second holds the parts that can be affected
 by user actions.
 
-In this case the style is found in the Widget [Menu]
+In this case the style is found in the Widget [struct@Menu]
 and the menu tree is found in WidgetState [MenuState].
 
 The menu tree is built with one [MenuItem] per possible selection.
@@ -15,24 +15,173 @@ When a menu item is selected, this generates an event which
 will be stored in MenuState.events.
 
 To define a menu, see examples in [MenuState].
+
+[PopupMenu] draws the same tree as a context menu, and [CommandPalette]
+searches it by name.
 */
 
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Margin, Rect},
-    style::{Color, Style},
+    layout::{Margin, Position, Rect},
+    style::{Color, Modifier, Style},
+    symbols::line,
     text::{Line, Span},
     widgets::{StatefulWidget, Widget},
 };
 use ratatui_widgets::{block::Block, borders::Borders, clear::Clear};
-use std::{borrow::Cow, marker::PhantomData};
+use std::{
+    any::Any,
+    borrow::Cow,
+    marker::PhantomData,
+    sync::{
+        mpsc::{self, Receiver, Sender, TryRecvError},
+        Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+mod backend;
+mod keymap;
+mod macros;
+mod palette;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use backend::MenuInput;
+#[cfg(feature = "termwiz")]
+pub use backend::TermwizInput;
+pub use keymap::{EventOutcome, Key, KeyChord, MenuAction, MenuKeymap, ParseKeyError};
+pub use palette::{CommandPalette, PaletteState};
+#[cfg(feature = "serde")]
+pub use serialize::LoadError;
+#[cfg(feature = "derive")]
+pub use tui_menu_derive::Menu;
 
-/// Events this widget produce
-/// Now only emit Selected, may add few in future
+/// Events this widget produce. More may be added, so match it with a
+/// wildcard arm
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum MenuEvent<T> {
     /// Item selected, with its data attached
     Selected(T),
+    /// Item highlighted by navigation, with its data attached. Group has
+    /// no data, so it only emits [MenuEvent::GroupOpened]
+    Highlighted(T),
+    /// Group's drop down opened, with the path to the group
+    GroupOpened(MenuPath),
+    /// Group's drop down closed, with the path to the group
+    GroupClosed(MenuPath),
+    /// Menu closed by [MenuState::reset] or [MenuState::pop] without
+    /// selecting any item
+    Cancelled,
+    /// Checkbox item toggled, with its data and new checked state attached
+    Toggled(T, bool),
+    /// Disabled item activated, e.g. by click or shortcut, with its data
+    /// attached. Useful to tell user why it is disabled
+    DisabledActivated(T),
+}
+
+/// Address of an item in menu tree, the child index at each level from
+/// root items down, e.g. `[0, 2]` is the third child of the first root item
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::MenuPath;
+///
+/// let path = MenuPath::new([0, 2]);
+/// assert_eq!(path.indices(), &[0, 2]);
+/// assert_eq!(path.parent(), Some(MenuPath::new([0])));
+/// assert_eq!(path.child(1), MenuPath::new([0, 2, 1]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuPath(Vec<usize>);
+
+impl MenuPath {
+    pub fn new(indices: impl Into<Vec<usize>>) -> Self {
+        Self(indices.into())
+    }
+
+    /// child index at each level
+    pub fn indices(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// depth of the item, 1 for root items, 0 for empty path
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// whether path is empty, it addresses nothing
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// path of the group containing this item, None for empty path
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.0.split_last()?;
+        Some(Self::new(parent))
+    }
+
+    /// path of child at `idx` of this item
+    pub fn child(&self, idx: usize) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(idx);
+        Self(indices)
+    }
+}
+
+impl From<Vec<usize>> for MenuPath {
+    fn from(indices: Vec<usize>) -> Self {
+        Self(indices)
+    }
+}
+
+impl From<&[usize]> for MenuPath {
+    fn from(indices: &[usize]) -> Self {
+        Self::new(indices)
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for MenuPath {
+    fn from(indices: [usize; N]) -> Self {
+        Self::new(indices)
+    }
+}
+
+/// Mouse action reported to [MenuState::handle_mouse]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseAction {
+    /// button pressed
+    Click,
+    /// pointer moved
+    Hover,
+    /// wheel scrolled up
+    ScrollUp,
+    /// wheel scrolled down
+    ScrollDown,
+}
+
+/// Where menu bar is, which decides the direction drop downs open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BarAnchor {
+    /// bar at top, drop downs open below it
+    #[default]
+    Top,
+    /// bar at bottom, e.g. in status line, drop downs open above it
+    Bottom,
+}
+
+/// How root items of [struct@Menu] are laid out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// menu bar, root items left to right
+    #[default]
+    Horizontal,
+    /// sidebar, root items top to bottom in the area, groups fly out to
+    /// the right. Navigation is same as [PopupMenu]
+    Vertical,
 }
 
 /// The state for menu, keep track of runtime info
@@ -41,6 +190,50 @@ pub struct MenuState<T> {
     root_item: MenuItem<T>,
     /// stores events generated in one frame
     events: Vec<MenuEvent<T>>,
+    /// areas drawn in last render, used for mouse hit test
+    areas: RenderedAreas,
+    /// set when inside [MenuState::navigate], so nested calls don't emit
+    /// events twice
+    navigating: bool,
+    /// an item selected since menu opened, so closing it is not cancel
+    selected: bool,
+    /// layout of last render, decides what arrow keys do
+    mode: NavigationMode,
+    /// chars typed by [MenuState::type_ahead]
+    type_ahead: TypeAhead,
+    /// key bindings of [MenuState::handle_key]
+    keymap: MenuKeymap,
+}
+
+/// Prefix typed to jump in current level
+struct TypeAhead {
+    buffer: String,
+    /// time of last typed char
+    last: Option<Instant>,
+    /// idle interval after which buffer is cleared
+    timeout: Duration,
+}
+
+/// How menu is laid out, see [MenuState::up] for the bar semantics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NavigationMode {
+    /// menu bar, up and down are mirrored for bottom bar
+    Bar(BarAnchor),
+    /// first level is vertical, e.g. [PopupMenu] and sidebar of
+    /// [Orientation::Vertical]. Up and down move within
+    /// a level, right and left enter and leave sub menus
+    Vertical,
+}
+
+/// Areas drawn by [struct@Menu] in last render
+#[derive(Default)]
+struct RenderedAreas {
+    /// menu bar and drop down panels, clicks inside them are consumed
+    panels: Vec<Rect>,
+    /// item areas with the index path from root, in drawing order
+    items: Vec<(Rect, Vec<usize>)>,
+    /// drop down panels with the index path of their group
+    dropdowns: Vec<(Rect, Vec<usize>)>,
 }
 
 impl<T: Clone> MenuState<T> {
@@ -67,9 +260,36 @@ impl<T: Clone> MenuState<T> {
         Self {
             root_item,
             events: Default::default(),
+            areas: Default::default(),
+            navigating: false,
+            selected: false,
+            mode: NavigationMode::Bar(BarAnchor::Top),
+            type_ahead: TypeAhead {
+                buffer: String::new(),
+                last: None,
+                timeout: Duration::from_secs(1),
+            },
+            keymap: MenuKeymap::arrows(),
         }
     }
 
+    /// update idle interval after which [MenuState::type_ahead] starts a
+    /// new prefix, default is 1 second
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let state = MenuState::new(vec![MenuItem::item("Foo", "label_foo")])
+    ///     .type_ahead_timeout(Duration::from_millis(500));
+    /// ```
+    pub fn type_ahead_timeout(mut self, timeout: Duration) -> Self {
+        self.type_ahead.timeout = timeout;
+        self
+    }
+
     /// active the menu, this will select the first item
     ///
     /// # Example
@@ -92,12 +312,96 @@ impl<T: Clone> MenuState<T> {
     /// ```
     ///
     pub fn activate(&mut self) {
-        self.root_item.highlight_next();
+        self.navigate(|state| {
+            state.root_item.highlight_next();
+        })
+    }
+
+    /// activate as [PopupMenu], so navigation is vertical before it is
+    /// rendered, e.g. up and down move within first level. Also for sidebar
+    /// of [Orientation::Vertical].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::item("Copy", "label_copy"),
+    ///     MenuItem::item("Paste", "label_paste"),
+    /// ]);
+    ///
+    /// state.activate_popup();
+    /// state.down();
+    /// assert_eq!(state.highlight().unwrap().data, Some("label_paste"));
+    /// ```
+    pub fn activate_popup(&mut self) {
+        self.mode = NavigationMode::Vertical;
+        self.activate();
+    }
+
+    /// run navigation `f`, then emit events for the highlight changes it made
+    fn navigate<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        if self.navigating {
+            return f(self);
+        }
+
+        let before = self.root_item.highlight_path();
+        let before_groups = self.root_item.open_groups();
+        let events_start = self.events.len();
+        let was_selected = std::mem::take(&mut self.selected);
+
+        self.navigating = true;
+        let result = f(self);
+        self.navigating = false;
+
+        let after = self.root_item.highlight_path();
+        if after == before {
+            self.selected |= was_selected;
+            return result;
+        }
+        self.root_item.scroll_to_highlight();
+        let after_groups = self.root_item.open_groups();
+        let mut events = vec![];
+
+        // close deeper groups first
+        for group in before_groups.iter().rev() {
+            if !after_groups.contains(group) {
+                if let Some(item) = self.root_item.item_at_mut(group) {
+                    item.expire_children();
+                }
+                events.push(MenuEvent::GroupClosed(MenuPath::new(group.clone())));
+            }
+        }
+        for group in after_groups.iter() {
+            if !before_groups.contains(group) {
+                if let Some(item) = self.root_item.item_at_mut(group) {
+                    item.load_children();
+                }
+                events.push(MenuEvent::GroupOpened(MenuPath::new(group.clone())));
+            }
+        }
+
+        if after.is_empty() {
+            if !was_selected && !self.selected {
+                events.push(MenuEvent::Cancelled);
+            }
+            self.selected = false;
+        } else if let Some(data) = self.root_item.item_at(&after).and_then(|i| i.data.clone()) {
+            events.push(MenuEvent::Highlighted(data));
+        }
+
+        // navigation happens before the selection it leads to, e.g. click
+        // highlights item then selects it
+        self.events.splice(events_start..events_start, events);
+
+        result
     }
 
     /// Check if menu is active
     pub fn is_active(&self) -> bool {
-        self.root_item.highlight().is_some()
+        // root item is always highlighted, check its children
+        self.root_item.highlight_child().is_some()
     }
 
     /// trigger up movement
@@ -123,27 +427,63 @@ impl<T: Clone> MenuState<T> {
     ///                                 sub sub item 2
     ///
     /// up does nothing
+    ///
+    /// With [BarAnchor::Bottom], drop downs open above the bar, and up
+    /// behaves like down mirrored. In [PopupMenu], up highlights the prev
+    /// item in current level.
     pub fn up(&mut self) {
+        self.navigate(|state| match state.mode {
+            NavigationMode::Bar(BarAnchor::Top) => state.toward_bar(),
+            NavigationMode::Bar(BarAnchor::Bottom) => state.away_from_bar(),
+            NavigationMode::Vertical => state.prev(),
+        })
+    }
+
+    /// move highlight toward menu bar, pop the drop down if already at the
+    /// edge next to bar
+    fn toward_bar(&mut self) {
+        let bottom = self.mode == NavigationMode::Bar(BarAnchor::Bottom);
         match self.active_depth() {
             0 | 1 => {
                 // do nothing
             }
-            2 => match self
-                .root_item
-                .highlight_child()
-                .and_then(|child| child.highlight_child_index())
-            {
-                // case 1
-                Some(0) => {
+            2 => {
+                let at_edge = self.root_item.highlight_child().is_some_and(|child| {
+                    child.highlight_child_index()
+                        == if bottom {
+                            child.last_selectable_index()
+                        } else {
+                            child.first_selectable_index()
+                        }
+                });
+                if at_edge {
                     self.pop();
-                }
-                _ => {
+                } else if bottom {
+                    self.next();
+                } else {
                     self.prev();
                 }
-            },
-            _ => {
-                self.prev();
             }
+            _ if bottom => self.next(),
+            _ => self.prev(),
+        }
+    }
+
+    /// move highlight away from menu bar, open the drop down if on bar
+    fn away_from_bar(&mut self) {
+        let bottom = self.mode == NavigationMode::Bar(BarAnchor::Bottom);
+        if self.active_depth() == 1 {
+            if !bottom {
+                self.push();
+            } else if let Some(group) = self.root_item.highlight_mut() {
+                if group.is_enabled() {
+                    group.highlight_last_child();
+                }
+            }
+        } else if bottom {
+            self.prev();
+        } else {
+            self.next();
         }
     }
 
@@ -169,12 +509,16 @@ impl<T: Clone> MenuState<T> {
     ///                   sub item 2
     ///
     /// down highlights "sub item 2"
+    ///
+    /// With [BarAnchor::Bottom], drop downs open above the bar, and down
+    /// behaves like up mirrored. In [PopupMenu], down highlights the next
+    /// item in current level.
     pub fn down(&mut self) {
-        if self.active_depth() == 1 {
-            self.push();
-        } else {
-            self.next();
-        }
+        self.navigate(|state| match state.mode {
+            NavigationMode::Bar(BarAnchor::Top) => state.away_from_bar(),
+            NavigationMode::Bar(BarAnchor::Bottom) => state.toward_bar(),
+            NavigationMode::Vertical => state.next(),
+        })
     }
 
     /// trigger left movement
@@ -199,17 +543,26 @@ impl<T: Clone> MenuState<T> {
     ///                   sub item 2  > sub sub item 2
     ///
     /// left pop "sub sub group"
+    ///
+    /// In [PopupMenu], left pops current sub menu, and does nothing on the
+    /// first level.
     pub fn left(&mut self) {
-        if self.active_depth() == 0 {
-            // do nothing
-        } else if self.active_depth() == 1 {
-            self.prev();
-        } else if self.active_depth() == 2 {
-            self.pop();
-            self.prev();
-        } else {
-            self.pop();
-        }
+        self.navigate(|state| {
+            if state.mode == NavigationMode::Vertical {
+                if state.active_depth() > 1 {
+                    state.pop();
+                }
+            } else if state.active_depth() == 0 {
+                // do nothing
+            } else if state.active_depth() == 1 {
+                state.prev();
+            } else if state.active_depth() == 2 {
+                state.pop();
+                state.prev();
+            } else {
+                state.pop();
+            }
+        })
     }
 
     /// trigger right movement
@@ -234,21 +587,27 @@ impl<T: Clone> MenuState<T> {
     ///                 > sub item 2 +
     /// right pushes "sub sub item 2". this differs from case 2 that
     /// current highlighted item can be expanded
+    ///
+    /// In [PopupMenu], right pushes highlighted group.
     pub fn right(&mut self) {
-        if self.active_depth() == 0 {
-            // do nothing
-        } else if self.active_depth() == 1 {
-            self.next();
-        } else if self.active_depth() == 2 {
-            if self.push().is_none() {
-                // special handling, make menu navigation
-                // more productive
-                self.pop();
-                self.next();
+        self.navigate(|state| {
+            if state.mode == NavigationMode::Vertical {
+                state.push();
+            } else if state.active_depth() == 0 {
+                // do nothing
+            } else if state.active_depth() == 1 {
+                state.next();
+            } else if state.active_depth() == 2 {
+                if state.push().is_none() {
+                    // special handling, make menu navigation
+                    // more productive
+                    state.pop();
+                    state.next();
+                }
+            } else {
+                state.push();
             }
-        } else {
-            self.push();
-        }
+        })
     }
 
     /// highlight the prev item in current group
@@ -284,42 +643,124 @@ impl<T: Clone> MenuState<T> {
         depth
     }
 
-    /// How many dropdown to render, including preview
-    /// NOTE: If current group contains sub-group, in order to keep ui consistent,
-    ///   even the sub-group not selected, its space is counted
-    fn dropdown_count(&self) -> u16 {
-        let mut node = &self.root_item;
-        let mut count = 0;
-        loop {
-            match node.highlight_child() {
-                None => {
-                    return count;
-                }
-                Some(highlight_child) => {
-                    if highlight_child.is_group() {
-                        // highlighted child is a group, then it's children is previewed
-                        count += 1;
-                    } else if node.children.iter().any(|c| c.is_group()) {
-                        // if highlighted item is not a group, but if sibling contains group
-                        // in order to keep ui consistency, also count it
-                        count += 1;
-                    }
-
-                    node = highlight_child;
+    /// select current highlight item, if it has children
+    /// then push
+    pub fn select(&mut self) {
+        self.navigate(|state| {
+            let path = state.root_item.highlight_path();
+            if let Some(item) = state.root_item.item_at(&path) {
+                if item.is_group() {
+                    state.push();
+                } else {
+                    state.activate_at(&path);
                 }
             }
+        })
+    }
+
+    /// activate the non group item at path, this emits
+    /// - [MenuEvent::Selected] for normal item, and radio item after it is
+    ///   checked
+    /// - [MenuEvent::Toggled] for checkbox, after its state flipped
+    /// - [MenuEvent::DisabledActivated] for disabled item
+    fn activate_at(&mut self, path: &[usize]) {
+        let Some(item) = self.root_item.item_at_mut(path) else {
+            return;
+        };
+        let Some(data) = item.data.clone() else {
+            return;
+        };
+
+        if !item.is_enabled() {
+            self.events.push(MenuEvent::DisabledActivated(data));
+        } else if item.kind == MenuItemKind::Checkbox {
+            item.checked = !item.checked;
+            self.events.push(MenuEvent::Toggled(data, item.checked));
+        } else if let MenuItemKind::Radio(group) = &item.kind {
+            let group = group.clone();
+            self.check_radio_at(path, &group);
+            self.events.push(MenuEvent::Selected(data));
+            self.selected = true;
+        } else {
+            self.events.push(MenuEvent::Selected(data));
+            self.selected = true;
         }
     }
 
-    /// select current highlight item, if it has children
-    /// then push
-    pub fn select(&mut self) {
-        if let Some(item) = self.root_item.highlight_mut() {
-            if !item.children.is_empty() {
-                self.push();
-            } else if let Some(ref data) = item.data {
-                self.events.push(MenuEvent::Selected(data.clone()));
-            }
+    /// set checked state of all checkbox items whose data matches `pred`,
+    /// this doesn't emit events. Useful to keep in sync with app state.
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("View", vec![
+    ///         MenuItem::checkbox("Word wrap", "label_wrap", false),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert!(state.set_checked(|data| *data == "label_wrap", true));
+    /// assert_eq!(state.is_checked(|data| *data == "label_wrap"), Some(true));
+    /// ```
+    pub fn set_checked(&mut self, pred: impl Fn(&T) -> bool, checked: bool) -> bool {
+        self.root_item.set_checked_by(&pred, checked)
+    }
+
+    /// checked state of the first checkbox or radio item whose data matches
+    /// `pred`
+    pub fn is_checked(&self, pred: impl Fn(&T) -> bool) -> Option<bool> {
+        let path = self.root_item.find_path(&|item| {
+            matches!(item.kind, MenuItemKind::Checkbox | MenuItemKind::Radio(_))
+                && item.data.as_ref().is_some_and(&pred)
+        })?;
+        self.root_item.item_at(&path).map(|item| item.checked)
+    }
+
+    /// check the first radio item whose data matches `pred`, other items in
+    /// its group are unchecked. This doesn't emit events, useful to restore
+    /// the choice at startup.
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Theme", vec![
+    ///         MenuItem::radio("Light", "theme", "label_light", true),
+    ///         MenuItem::radio("Dark", "theme", "label_dark", false),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert!(state.check_radio(|data| *data == "label_dark"));
+    /// assert_eq!(state.is_checked(|data| *data == "label_light"), Some(false));
+    /// ```
+    pub fn check_radio(&mut self, pred: impl Fn(&T) -> bool) -> bool {
+        let Some(path) = self.root_item.find_path(&|item| {
+            matches!(item.kind, MenuItemKind::Radio(_)) && item.data.as_ref().is_some_and(&pred)
+        }) else {
+            return false;
+        };
+
+        if let Some(MenuItemKind::Radio(group)) =
+            self.root_item.item_at(&path).map(|item| item.kind.clone())
+        {
+            self.check_radio_at(&path, &group);
+        }
+        true
+    }
+
+    /// check radio item at path, and uncheck others in the same group
+    fn check_radio_at(&mut self, path: &[usize], group: &str) {
+        self.root_item.uncheck_radio_group(group);
+        if let Some(item) = self.root_item.item_at_mut(path) {
+            item.checked = true;
         }
     }
 
@@ -327,23 +768,63 @@ impl<T: Clone> MenuState<T> {
     /// Return: Some if entered deeper level
     ///         None if nothing happen
     pub fn push(&mut self) -> Option<()> {
-        self.root_item.highlight_mut()?.highlight_first_child()
+        self.navigate(|state| {
+            let item = state.root_item.highlight_mut()?;
+            if !item.is_enabled() {
+                return None;
+            }
+            item.load_children();
+            item.highlight_first_child()
+        })
+    }
+
+    /// evaluate the predicates set by [MenuItem::enabled_when] against app
+    /// context. Call it before render and input handling whenever context
+    /// changes. Predicates expecting another context type are not touched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// struct Clipboard(Option<String>);
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Edit", vec![
+    ///         MenuItem::item("Paste", "label_paste")
+    ///             .enabled_when(|clipboard: &Clipboard| clipboard.0.is_some()),
+    ///     ]),
+    /// ]);
+    ///
+    /// state.update_context(&Clipboard(None));
+    /// state.activate();
+    /// state.down();
+    /// // the only item is disabled, so nothing to highlight
+    /// assert_eq!(state.highlight().unwrap().name(), "Edit");
+    /// ```
+    pub fn update_context<C: Any>(&mut self, ctx: &C) {
+        self.root_item.update_context(ctx);
     }
 
     /// pop the current menu group. move one layer up
     pub fn pop(&mut self) {
-        if let Some(item) = self.root_item.highlight_mut() {
-            item.clear_highlight();
-        }
+        self.navigate(|state| {
+            if let Some(item) = state.root_item.highlight_mut() {
+                item.clear_highlight();
+            }
+        })
     }
 
     /// clear all highlighted items. This is useful
     /// when the menu bar lose focus
     pub fn reset(&mut self) {
-        self.root_item
-            .children
-            .iter_mut()
-            .for_each(|c| c.clear_highlight());
+        self.navigate(|state| {
+            state
+                .root_item
+                .children
+                .iter_mut()
+                .for_each(|c| c.clear_highlight());
+        })
     }
 
     /// client should drain events each frame, otherwise user action
@@ -352,81 +833,1261 @@ impl<T: Clone> MenuState<T> {
         std::mem::take(&mut self.events).into_iter()
     }
 
-    /// return current highlighted item's reference
-    pub fn highlight(&self) -> Option<&MenuItem<T>> {
-        self.root_item.highlight()
-    }
-}
-
-/// MenuItem is the node in menu tree. If children is not
-/// empty, then this item is the group item.
-pub struct MenuItem<T> {
-    name: Cow<'static, str>,
-    pub data: Option<T>,
-    children: Vec<MenuItem<T>>,
-    is_highlight: bool,
-}
-
-impl<T> MenuItem<T> {
-    /// helper function to create a non group item.
-    pub fn item(name: impl Into<Cow<'static, str>>, data: T) -> Self {
-        Self {
-            name: name.into(),
-            data: Some(data),
-            is_highlight: false,
-            children: vec![],
-        }
-    }
-
-    /// helper function to create a group item.
+    /// path of current highlighted item, empty if menu is not active
     ///
     /// # Example
     ///
     /// ```
-    /// use tui_menu::MenuItem;
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
     ///
-    /// let item = MenuItem::<&'static str>::group("group", vec![
-    ///     MenuItem::item("foo", "label_foo"),
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Group", vec![
+    ///         MenuItem::item("Bar 1", "label_bar_1"),
+    ///         MenuItem::item("Bar 2", "label_bar_2"),
+    ///     ])
     /// ]);
+    /// assert!(state.highlight_path().is_empty());
     ///
-    /// assert!(item.is_group());
+    /// state.activate();
+    /// state.down();
+    /// assert_eq!(state.highlight_path(), MenuPath::new([0, 0]));
+    /// ```
+    pub fn highlight_path(&self) -> MenuPath {
+        MenuPath(self.root_item.highlight_path())
+    }
+
+    /// highlight the item at `path`, every group along the way is opened.
+    /// Navigation events are emitted like user moved there.
+    ///
+    /// Return: false if path doesn't exist, or any item on it can't be
+    ///         highlighted, e.g. disabled. Highlight is not changed then
+    ///
+    /// # Example
     ///
     /// ```
-    pub fn group(name: impl Into<Cow<'static, str>>, children: Vec<Self>) -> Self {
-        Self {
-            name: name.into(),
-            data: None,
-            is_highlight: false,
-            children,
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Group", vec![
+    ///         MenuItem::item("Bar 1", "label_bar_1"),
+    ///         MenuItem::item("Bar 2", "label_bar_2"),
+    ///     ])
+    /// ]);
+    /// assert!(state.set_highlight_path(&MenuPath::new([0, 1])));
+    /// assert_eq!(state.highlight().unwrap().data, Some("label_bar_2"));
+    /// assert!(!state.set_highlight_path(&MenuPath::new([0, 5])));
+    /// ```
+    pub fn set_highlight_path(&mut self, path: &MenuPath) -> bool {
+        let mut item = &self.root_item;
+        for &idx in path.indices() {
+            match item.children.get(idx) {
+                Some(child) if child.is_selectable() => item = child,
+                _ => return false,
+            }
         }
-    }
 
-    #[cfg(test)]
-    fn with_highlight(mut self, highlight: bool) -> Self {
-        self.is_highlight = highlight;
-        self
+        self.navigate(|state| state.root_item.highlight_at(path.indices()));
+        true
     }
 
-    /// whether this item is group
-    pub fn is_group(&self) -> bool {
-        !self.children.is_empty()
+    /// item at `path`, None if it doesn't exist or path is empty
+    pub fn item_at(&self, path: &MenuPath) -> Option<&MenuItem<T>> {
+        if path.is_empty() {
+            return None;
+        }
+        self.root_item.item_at(path.indices())
     }
 
-    /// get current item's name
-    fn name(&self) -> &str {
-        &self.name
-    }
+    /// path of first item whose data matches `pred`, depth first
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Group", vec![
+    ///         MenuItem::item("Bar 1", "label_bar_1"),
+    ///         MenuItem::item("Bar 2", "label_bar_2"),
+    ///     ])
+    /// ]);
+    /// let path = state.find(|data| *data == "label_bar_2").unwrap();
+    /// assert_eq!(path, MenuPath::new([0, 1]));
+    /// assert_eq!(state.item_at(&path).unwrap().name(), "Bar 2");
+    /// ```
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<MenuPath> {
+        self.root_item
+            .find_path(&|item| item.data.as_ref().is_some_and(&pred))
+            .map(MenuPath)
+    }
+
+    /// path of item by names from root item down, e.g.
+    /// `["File", "Open recent"]`. Names are the displayed ones, without
+    /// mnemonic marker
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("&File", vec![
+    ///         MenuItem::item("&Open", "label_open"),
+    ///     ])
+    /// ]);
+    /// assert_eq!(state.find_by_names(&["File", "Open"]), Some(MenuPath::new([0, 0])));
+    /// ```
+    pub fn find_by_names(&self, names: &[&str]) -> Option<MenuPath> {
+        let mut item = &self.root_item;
+        let mut path = vec![];
+        for name in names {
+            let idx = item
+                .children
+                .iter()
+                .position(|child| !child.is_decoration() && child.name() == *name)?;
+            path.push(idx);
+            item = &item.children[idx];
+        }
+        Some(MenuPath(path))
+    }
+
+    /// insert `item` at `path`, later siblings shift down. Index can be
+    /// the children count of its parent to append. Highlight stays on the
+    /// same item. No events are emitted by tree mutation.
+    ///
+    /// Return: false if parent doesn't exist or index is out of range
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Open recent", vec![MenuItem::item("a.txt", "label_a")]),
+    /// ]);
+    /// state.set_highlight_path(&MenuPath::new([0, 0]));
+    ///
+    /// assert!(state.insert(&MenuPath::new([0, 0]), MenuItem::item("b.txt", "label_b")));
+    /// assert_eq!(state.highlight().unwrap().data, Some("label_a"));
+    /// assert_eq!(state.highlight_path(), MenuPath::new([0, 1]));
+    /// ```
+    pub fn insert(&mut self, path: &MenuPath, mut item: MenuItem<T>) -> bool {
+        let Some((&idx, parent)) = path.indices().split_last() else {
+            return false;
+        };
+        let Some(group) = self.root_item.item_at_mut(parent) else {
+            return false;
+        };
+        if idx > group.children.len() {
+            return false;
+        }
+
+        item.clear_highlight();
+        group.children.insert(idx, item);
+        true
+    }
+
+    /// remove item at `path` and return it. If it is highlighted, the
+    /// highlight moves to the nearest sibling can be highlighted, the next
+    /// one first, or stays on the parent if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Window", vec![
+    ///         MenuItem::item("a", "label_a"),
+    ///         MenuItem::item("b", "label_b"),
+    ///     ]),
+    /// ]);
+    /// state.set_highlight_path(&MenuPath::new([0, 0]));
+    ///
+    /// let removed = state.remove(&MenuPath::new([0, 0])).unwrap();
+    /// assert_eq!(removed.name(), "a");
+    /// assert_eq!(state.highlight().unwrap().data, Some("label_b"));
+    /// ```
+    pub fn remove(&mut self, path: &MenuPath) -> Option<MenuItem<T>> {
+        let (&idx, parent) = path.indices().split_last()?;
+        let group = self.root_item.item_at_mut(parent)?;
+        if idx >= group.children.len() {
+            return None;
+        }
+
+        let mut item = group.children.remove(idx);
+        if item.is_highlight {
+            group.highlight_near(idx);
+            item.clear_highlight();
+            self.root_item.scroll_to_highlight();
+        }
+        Some(item)
+    }
+
+    /// replace item at `path` and return the old one. If the old one is
+    /// highlighted, the new one takes its highlight, and deeper highlights
+    /// are kept where children still can be highlighted.
+    pub fn replace(&mut self, path: &MenuPath, mut item: MenuItem<T>) -> Option<MenuItem<T>> {
+        let (&idx, parent) = path.indices().split_last()?;
+        let group = self.root_item.item_at_mut(parent)?;
+        let target = group.children.get_mut(idx)?;
+
+        item.clear_highlight();
+        let mut old = std::mem::replace(target, item);
+        if old.is_highlight {
+            if target.is_selectable() {
+                target.is_highlight = true;
+                target.highlight_valid(&old.highlight_path());
+            } else {
+                group.highlight_near(idx);
+            }
+            old.clear_highlight();
+            self.root_item.scroll_to_highlight();
+        }
+        Some(old)
+    }
+
+    /// move item at `from` to `to`, `to` is the path after the item is
+    /// removed from `from`. Highlight stays on the same item, groups along
+    /// its new path are opened if it is highlighted.
+    ///
+    /// Return: false if either path doesn't exist, or `to` is inside the
+    ///         moved item. Tree is not changed then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("Open recent", vec![
+    ///         MenuItem::item("a.txt", "label_a"),
+    ///         MenuItem::item("b.txt", "label_b"),
+    ///     ]),
+    /// ]);
+    ///
+    /// // most recent first
+    /// assert!(state.move_item(&MenuPath::new([0, 1]), &MenuPath::new([0, 0])));
+    /// assert_eq!(state.item_at(&MenuPath::new([0, 0])).unwrap().name(), "b.txt");
+    /// ```
+    pub fn move_item(&mut self, from: &MenuPath, to: &MenuPath) -> bool {
+        let (Some((&from_idx, from_parent)), Some((&to_idx, to_parent))) =
+            (from.indices().split_last(), to.indices().split_last())
+        else {
+            return false;
+        };
+        if to.len() > from.len() && to.indices().starts_with(from.indices()) {
+            return false;
+        }
+        let Some(group) = self.root_item.item_at_mut(from_parent) else {
+            return false;
+        };
+        if from_idx >= group.children.len() {
+            return false;
+        }
+
+        let chain = self.root_item.highlight_path();
+        let item = self
+            .root_item
+            .item_at_mut(from_parent)
+            .unwrap()
+            .children
+            .remove(from_idx);
+        let Some(dest) = self
+            .root_item
+            .item_at_mut(to_parent)
+            .filter(|dest| to_idx <= dest.children.len())
+        else {
+            // put it back
+            let group = self.root_item.item_at_mut(from_parent).unwrap();
+            group.children.insert(from_idx, item);
+            return false;
+        };
+        dest.children.insert(to_idx, item);
+
+        if chain.starts_with(from.indices()) {
+            let chain = [to.indices(), &chain[from.len()..]].concat();
+            self.root_item.highlight_at(&chain);
+            self.root_item.scroll_to_highlight();
+        }
+        true
+    }
+
+    /// update name of item at `path`, `&` marks mnemonic like
+    /// [MenuItem::item] does
+    ///
+    /// Return: false if item doesn't exist
+    pub fn set_name(&mut self, path: &MenuPath, name: impl Into<Cow<'static, str>>) -> bool {
+        match self.item_at_mut(path) {
+            Some(item) => {
+                item.set_name(name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// update data of item at `path`
+    ///
+    /// Return: false if item doesn't exist
+    pub fn set_data(&mut self, path: &MenuPath, data: T) -> bool {
+        match self.item_at_mut(path) {
+            Some(item) => {
+                item.data = Some(data);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// drop loaded children of lazy group at `path`, see [MenuItem::lazy].
+    /// It loads again right away if the group is open, otherwise on next
+    /// open.
+    ///
+    /// Return: false if there is no lazy group at `path`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<String>::new(vec![
+    ///     MenuItem::lazy("Switch branch", |sender| {
+    ///         let branches = ["main", "dev"];
+    ///         sender.send(branches.map(|b| MenuItem::item(b, b.to_string())).into());
+    ///     }),
+    /// ]);
+    ///
+    /// state.activate();
+    /// state.push();
+    /// assert_eq!(state.highlight().unwrap().name(), "main");
+    ///
+    /// // e.g. after a new branch is created
+    /// assert!(state.invalidate(&MenuPath::new([0])));
+    /// ```
+    pub fn invalidate(&mut self, path: &MenuPath) -> bool {
+        let Some(item) = self.item_at_mut(path) else {
+            return false;
+        };
+        let Some(lazy) = item.lazy.as_mut() else {
+            return false;
+        };
+        lazy.loaded = false;
+        lazy.pending = None;
+
+        if item.is_highlight {
+            item.children.iter_mut().for_each(|c| c.clear_highlight());
+            item.load_children();
+        }
+        true
+    }
+
+    /// item at non empty `path`
+    fn item_at_mut(&mut self, path: &MenuPath) -> Option<&mut MenuItem<T>> {
+        if path.is_empty() {
+            return None;
+        }
+        self.root_item.item_at_mut(path.indices())
+    }
+
+    /// return current highlighted item's reference
+    pub fn highlight(&self) -> Option<&MenuItem<T>> {
+        self.root_item.highlight()
+    }
+
+    /// return description of current highlighted item, e.g. to show in
+    /// status line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::item("Save", "label_save").description("Save the current buffer to disk"),
+    /// ]);
+    /// assert_eq!(state.highlight_description(), None);
+    ///
+    /// state.activate();
+    /// assert_eq!(state.highlight_description(), Some("Save the current buffer to disk"));
+    /// ```
+    pub fn highlight_description(&self) -> Option<&str> {
+        self.highlight().and_then(|item| item.get_description())
+    }
+
+    /// handle mouse event at terminal position (column, row), hit tested
+    /// against the areas drawn by last render.
+    /// - click on menu bar item opens it, click again closes it
+    /// - hover on item highlights it when menu is active
+    /// - click on item without children selects it
+    /// - click outside the menu resets it
+    /// - wheel over drop down scrolls it
+    ///
+    /// Return: true if event is consumed by menu, false if app should route it
+    ///         onward
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+    /// use tui_menu::{Menu, MenuEvent, MenuItem, MenuState, MouseAction};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::item("Foo", "label_foo"),
+    /// ]);
+    /// let area = Rect::new(0, 0, 20, 5);
+    /// let mut buf = Buffer::empty(area);
+    /// Menu::new().render(area, &mut buf, &mut state);
+    ///
+    /// // " Foo " starts after the leading space
+    /// assert!(state.handle_mouse(2, 0, MouseAction::Click));
+    /// assert!(matches!(state.drain_events().last(), Some(MenuEvent::Selected("label_foo"))));
+    /// ```
+    pub fn handle_mouse(&mut self, column: u16, row: u16, action: MouseAction) -> bool {
+        self.navigate(|state| {
+            let position = Position::new(column, row);
+
+            if let MouseAction::ScrollUp | MouseAction::ScrollDown = action {
+                let Some((_, path)) = state
+                    .areas
+                    .dropdowns
+                    .iter()
+                    .rev()
+                    .find(|(area, _)| area.contains(position))
+                else {
+                    return state
+                        .areas
+                        .panels
+                        .iter()
+                        .any(|area| area.contains(position));
+                };
+                if let Some(group) = state.root_item.item_at_mut(path) {
+                    group.scroll(if action == MouseAction::ScrollUp {
+                        -1
+                    } else {
+                        1
+                    });
+                }
+                return true;
+            }
+
+            // later drawn items are on top
+            let hit_path = state
+                .areas
+                .items
+                .iter()
+                .rev()
+                .find(|(area, _)| area.contains(position))
+                .map(|(_, path)| path.clone());
+
+            let Some(path) = hit_path else {
+                if state
+                    .areas
+                    .panels
+                    .iter()
+                    .any(|area| area.contains(position))
+                {
+                    return true;
+                }
+                if action == MouseAction::Click {
+                    state.reset();
+                }
+                return false;
+            };
+
+            let highlight_path = state.root_item.highlight_path();
+            match action {
+                MouseAction::Click => {
+                    let Some(item) = state.root_item.item_at(&path) else {
+                        return true;
+                    };
+
+                    if !item.is_enabled() {
+                        state.activate_at(&path);
+                    } else if path.len() == 1
+                        && item.is_group()
+                        && state.mode != NavigationMode::Vertical
+                        && highlight_path.starts_with(&path)
+                    {
+                        // click on opened menu bar group closes it
+                        state.reset();
+                    } else if item.is_group() {
+                        state.root_item.highlight_at(&path);
+                        // vertical first level is always open, so enter
+                        // the group instead
+                        if state.mode == NavigationMode::Vertical {
+                            state.push();
+                        }
+                    } else {
+                        state.root_item.highlight_at(&path);
+                        state.select();
+                    }
+                }
+                MouseAction::Hover => {
+                    // only follow pointer when menu is open, and keep deeper
+                    // levels open when pointer is on their parent
+                    let is_selectable = state
+                        .root_item
+                        .item_at(&path)
+                        .is_some_and(|item| item.is_selectable());
+                    if state.is_active() && is_selectable && !highlight_path.starts_with(&path) {
+                        state.root_item.highlight_at(&path);
+                    }
+                }
+                MouseAction::ScrollUp | MouseAction::ScrollDown => {}
+            }
+            true
+        })
+    }
+
+    /// find the item with matching shortcut anywhere in the tree, and
+    /// activate it like [MenuState::select] does, e.g. emit
+    /// [MenuEvent::Selected] for it. The menu doesn't need to be active.
+    /// Shortcuts are compared as [KeyChord], so case of modifiers doesn't
+    /// matter and aliases match, e.g. "Ctrl+Del" is "Ctrl+Delete". Text
+    /// which isn't a chord is compared case insensitively.
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuEvent, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("File", vec![
+    ///         MenuItem::item("Save", "label_save").shortcut("Ctrl+S"),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert!(state.dispatch_shortcut("ctrl+s"));
+    /// assert!(matches!(state.drain_events().next(), Some(MenuEvent::Selected("label_save"))));
+    /// ```
+    pub fn dispatch_shortcut(&mut self, key: &str) -> bool {
+        match key.parse::<KeyChord>() {
+            Ok(chord) => self.dispatch_chord(&chord),
+            Err(_) => self.activate_shortcut(|shortcut| shortcut.eq_ignore_ascii_case(key)),
+        }
+    }
+
+    /// activate the item whose shortcut is `chord`
+    fn dispatch_chord(&mut self, chord: &KeyChord) -> bool {
+        self.activate_shortcut(|shortcut| {
+            shortcut
+                .parse::<KeyChord>()
+                .is_ok_and(|shortcut| shortcut == *chord)
+        })
+    }
+
+    /// activate the first item whose shortcut `matches`
+    fn activate_shortcut(&mut self, matches: impl Fn(&str) -> bool) -> bool {
+        let path = self.root_item.find_path(&|item| {
+            !item.is_decoration()
+                && !item.is_group()
+                && item.shortcut.as_deref().is_some_and(&matches)
+        });
+
+        match path {
+            Some(path) => {
+                self.activate_at(&path);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// resolve mnemonic in the menu bar, this works even if menu is not
+    /// active. It is meant to be bound to Alt+char, e.g. Alt+F opens "&File".
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("&File", vec![
+    ///         MenuItem::item("&New", "label_new"),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert!(state.handle_alt_char('f'));
+    /// assert_eq!(state.highlight().unwrap().data.unwrap(), "label_new");
+    /// ```
+    pub fn handle_alt_char(&mut self, c: char) -> bool {
+        self.navigate(|state| state.activate_mnemonic(vec![], c))
+    }
+
+    /// resolve mnemonic within the currently open level, the matched item
+    /// is activated: group is opened, item is selected.
+    /// If more than one sibling share the mnemonic, each call highlights
+    /// the next one without activating it.
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuEvent, MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::<&'static str>::new(vec![
+    ///     MenuItem::group("&File", vec![
+    ///         MenuItem::item("&New", "label_new"),
+    ///         MenuItem::item("E&xit", "label_exit"),
+    ///     ]),
+    /// ]);
+    ///
+    /// state.activate();
+    /// assert!(state.handle_char('x'));
+    /// assert!(matches!(state.drain_events().last(), Some(MenuEvent::Selected("label_exit"))));
+    /// ```
+    pub fn handle_char(&mut self, c: char) -> bool {
+        self.navigate(|state| {
+            let mut level = state.root_item.highlight_path();
+            match level.len() {
+                0 => return false,
+                1 => {
+                    // menu bar item highlighted, its drop down is the open level
+                    if !state
+                        .root_item
+                        .item_at(&level)
+                        .is_some_and(|i| i.is_group())
+                    {
+                        level.clear();
+                    }
+                }
+                _ => {
+                    level.pop();
+                }
+            }
+
+            state.activate_mnemonic(level, c)
+        })
+    }
+
+    /// jump to the first item in current level whose name starts with the
+    /// typed prefix. Chars accumulate until no char is typed for the
+    /// timeout, measured by `now` supplied by app. Typing the same letter
+    /// repeatedly cycles through items starting with it.
+    ///
+    /// Return: true if any item matched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use tui_menu::{MenuState, MenuItem};
+    ///
+    /// let mut state = MenuState::new(vec![
+    ///     MenuItem::group("Open recent", vec![
+    ///         MenuItem::item("alpha.txt", "label_alpha"),
+    ///         MenuItem::item("beta.txt", "label_beta"),
+    ///         MenuItem::item("bravo.txt", "label_bravo"),
+    ///     ]),
+    /// ]);
+    /// state.activate();
+    /// state.down();
+    ///
+    /// let now = Instant::now();
+    /// assert!(state.type_ahead('b', now));
+    /// assert!(state.type_ahead('r', now));
+    /// assert_eq!(state.highlight().unwrap().data, Some("label_bravo"));
+    /// ```
+    pub fn type_ahead(&mut self, c: char, now: Instant) -> bool {
+        let type_ahead = &mut self.type_ahead;
+        if type_ahead
+            .last
+            .is_some_and(|last| now.saturating_duration_since(last) > type_ahead.timeout)
+        {
+            type_ahead.buffer.clear();
+        }
+        type_ahead.last = Some(now);
+        type_ahead.buffer.extend(c.to_lowercase());
+
+        let path = self.root_item.highlight_path();
+        let Some((&current, level)) = path.split_last() else {
+            return false;
+        };
+        let Some(group) = self.root_item.item_at(level) else {
+            return false;
+        };
+
+        // same letter repeated cycles from next item, otherwise the longer
+        // prefix may still match current item
+        let buffer = &self.type_ahead.buffer;
+        let mut chars = buffer.chars();
+        let first = chars.next();
+        let (prefix, start) = if chars.all(|c| Some(c) == first) {
+            (&buffer[..first.map_or(0, char::len_utf8)], current + 1)
+        } else {
+            (buffer.as_str(), current)
+        };
+
+        let len = group.children.len();
+        let Some(idx) = (start..start + len).map(|idx| idx % len).find(|&idx| {
+            let item = &group.children[idx];
+            item.is_selectable() && item.name().to_lowercase().starts_with(prefix)
+        }) else {
+            return false;
+        };
+
+        let path = [level, &[idx]].concat();
+        self.navigate(|state| state.root_item.highlight_at(&path));
+        true
+    }
+
+    /// resolve mnemonic among the children of item at `level`
+    fn activate_mnemonic(&mut self, level: Vec<usize>, c: char) -> bool {
+        let c = fold_case(c);
+        let Some(group) = self.root_item.item_at(&level) else {
+            return false;
+        };
+
+        let matches = group
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.is_selectable() && item.mnemonic == Some(c))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        let mut path = level;
+        match matches.as_slice() {
+            [] => return false,
+            [idx] => {
+                path.push(*idx);
+                self.root_item.highlight_at(&path);
+                if self.push().is_none() {
+                    self.select();
+                }
+            }
+            _ => {
+                // ambiguous, cycle through matches
+                let current = self.root_item.highlight_path().get(path.len()).copied();
+                let next = matches
+                    .iter()
+                    .copied()
+                    .find(|&idx| current.is_none_or(|current| idx > current))
+                    .unwrap_or(matches[0]);
+                path.push(next);
+                self.root_item.highlight_at(&path);
+            }
+        }
+        true
+    }
+}
+
+/// Type whose values are data of a menu tree, usually an action enum.
+/// With `derive` feature, it is implemented by `#[derive(Menu)]`.
+#[cfg_attr(feature = "derive", doc = "See [macro@Menu].")]
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{MenuItem, MenuState, MenuTree};
+///
+/// #[derive(Clone)]
+/// enum Action {
+///     New,
+///     Exit,
+/// }
+///
+/// impl MenuTree for Action {
+///     fn menu_tree() -> Vec<MenuItem<Self>> {
+///         vec![MenuItem::group("&File", vec![
+///             MenuItem::item("&New", Action::New),
+///             MenuItem::item("E&xit", Action::Exit),
+///         ])]
+///     }
+/// }
+///
+/// let state = MenuState::new(Action::menu_tree());
+/// ```
+pub trait MenuTree: Sized {
+    /// top level items of the menu tree
+    fn menu_tree() -> Vec<MenuItem<Self>>;
+}
+
+/// MenuItem is the node in menu tree. If children is not
+/// empty, then this item is the group item.
+pub struct MenuItem<T> {
+    name: Cow<'static, str>,
+    pub data: Option<T>,
+    children: Vec<MenuItem<T>>,
+    is_highlight: bool,
+    /// accelerator letter, stored in lower case
+    mnemonic: Option<char>,
+    /// char index of the mnemonic in name, which is underlined
+    mnemonic_index: Option<usize>,
+    /// shortcut hint, e.g. "Ctrl+S", rendered right aligned
+    shortcut: Option<Cow<'static, str>>,
+    /// longer help text, shown in status line or tooltip
+    description: Option<Cow<'static, str>>,
+    /// index of first child shown in drop down
+    scroll_offset: usize,
+    /// children rows shown in last render, 0 if not rendered yet
+    viewport: usize,
+    kind: MenuItemKind,
+    /// checked state for checkbox
+    checked: bool,
+    /// statically disabled
+    disabled: bool,
+    /// predicate on app context, None if context type mismatches
+    enabled_when: Option<EnabledPredicate>,
+    /// disabled by the last evaluation of `enabled_when`
+    context_disabled: bool,
+    /// children provider of lazy group
+    lazy: Option<Box<Lazy<T>>>,
+}
+
+/// type erased predicate, see [MenuItem::enabled_when]
+type EnabledPredicate = Box<dyn Fn(&dyn Any) -> Option<bool> + Send + Sync>;
+
+/// children provider, see [MenuItem::lazy]
+type Loader<T> = Box<dyn Fn(ChildrenSender<T>) + Send + Sync>;
+
+/// load state of lazy group
+struct Lazy<T> {
+    loader: Loader<T>,
+    /// keep loaded children for later opens
+    cache: bool,
+    /// children are loaded and still valid
+    loaded: bool,
+    /// children being loaded, Mutex keeps MenuItem Sync
+    pending: Option<Mutex<Receiver<Vec<MenuItem<T>>>>>,
+}
+
+/// Delivers children of a lazy group, see [MenuItem::lazy]. It can be
+/// moved to another thread.
+pub struct ChildrenSender<T>(Sender<Vec<MenuItem<T>>>);
+
+impl<T> ChildrenSender<T> {
+    /// deliver loaded children, they are shown from next render.
+    ///
+    /// Return: false if the group doesn't wait for them anymore, e.g. it
+    ///         is invalidated or removed
+    pub fn send(self, children: Vec<MenuItem<T>>) -> bool {
+        self.0.send(children).is_ok()
+    }
+}
+
+/// Kind of row a [MenuItem] renders as
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MenuItemKind {
+    /// item or group
+    Normal,
+    /// line between items
+    Separator,
+    /// title for the items below it
+    Section,
+    /// item with checked state
+    Checkbox,
+    /// item in a mutually exclusive group, with the group name
+    Radio(Cow<'static, str>),
+}
+
+impl<T> MenuItem<T> {
+    /// create item, the first `&` in name marks the next char as mnemonic
+    fn new(name: impl Into<Cow<'static, str>>, data: Option<T>, children: Vec<Self>) -> Self {
+        let (name, mnemonic_index) = parse_mnemonic(name.into());
+        let mnemonic = mnemonic_index
+            .and_then(|idx| name.chars().nth(idx))
+            .map(fold_case);
+
+        Self {
+            name,
+            data,
+            is_highlight: false,
+            children,
+            mnemonic,
+            mnemonic_index,
+            shortcut: None,
+            description: None,
+            scroll_offset: 0,
+            viewport: 0,
+            kind: MenuItemKind::Normal,
+            checked: false,
+            disabled: false,
+            enabled_when: None,
+            context_disabled: false,
+            lazy: None,
+        }
+    }
+
+    /// helper function to create a non group item.
+    ///
+    /// A `&` in name marks the next char as mnemonic, use `&&` for a
+    /// literal `&`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::item("&Open", "label_open");
+    /// assert_eq!(item.name(), "Open");
+    /// ```
+    pub fn item(name: impl Into<Cow<'static, str>>, data: T) -> Self {
+        Self::new(name, Some(data), vec![])
+    }
+
+    /// helper function to create a group item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::<&'static str>::group("group", vec![
+    ///     MenuItem::item("foo", "label_foo"),
+    /// ]);
+    ///
+    /// assert!(item.is_group());
+    ///
+    /// ```
+    pub fn group(name: impl Into<Cow<'static, str>>, children: Vec<Self>) -> Self {
+        Self::new(name, None, children)
+    }
+
+    /// helper function to create a checkbox item, selecting it flips the
+    /// checked state and emits [MenuEvent::Toggled].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::checkbox("Word wrap", "label_wrap", true);
+    /// assert!(item.is_checked());
+    /// ```
+    pub fn checkbox(name: impl Into<Cow<'static, str>>, data: T, checked: bool) -> Self {
+        let mut item = Self::new(name, Some(data), vec![]);
+        item.kind = MenuItemKind::Checkbox;
+        item.checked = checked;
+        item
+    }
+
+    /// helper function to create a radio item. Items with same `group`
+    /// anywhere in the tree are mutually exclusive, selecting one unchecks
+    /// the others and emits [MenuEvent::Selected].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::<&'static str>::group("Theme", vec![
+    ///     MenuItem::radio("Light", "theme", "label_light", true),
+    ///     MenuItem::radio("Dark", "theme", "label_dark", false),
+    /// ]);
+    /// ```
+    pub fn radio(
+        name: impl Into<Cow<'static, str>>,
+        group: impl Into<Cow<'static, str>>,
+        data: T,
+        checked: bool,
+    ) -> Self {
+        let mut item = Self::new(name, Some(data), vec![]);
+        item.kind = MenuItemKind::Radio(group.into());
+        item.checked = checked;
+        item
+    }
+
+    /// helper function to create a separator line, it can't be highlighted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::<&'static str>::group("File", vec![
+    ///     MenuItem::item("Save", "label_save"),
+    ///     MenuItem::separator(),
+    ///     MenuItem::item("Exit", "label_exit"),
+    /// ]);
+    /// ```
+    pub fn separator() -> Self {
+        let mut item = Self::new("", None, vec![]);
+        item.kind = MenuItemKind::Separator;
+        item
+    }
+
+    /// helper function to create a section title, it can't be highlighted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::<&'static str>::group("File", vec![
+    ///     MenuItem::item("Open", "label_open"),
+    ///     MenuItem::section("Recent"),
+    ///     MenuItem::item("file_1.txt", "label_file_1"),
+    /// ]);
+    /// ```
+    pub fn section(name: impl Into<Cow<'static, str>>) -> Self {
+        let mut item = Self::new(name, None, vec![]);
+        item.kind = MenuItemKind::Section;
+        item.mnemonic = None;
+        item.mnemonic_index = None;
+        item
+    }
+
+    /// helper function to create a group whose children are loaded when it
+    /// opens. `loader` gets a [ChildrenSender], which can deliver children
+    /// right away or later from another thread. A "Loading…" row is shown
+    /// until they arrive. Delivered children are polled by render.
+    ///
+    /// Loaded children are cached, see [MenuItem::cache] and
+    /// [MenuState::invalidate]. An empty result makes it a normal item
+    /// until invalidated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::<String>::lazy("Connect to host", |sender| {
+    ///     std::thread::spawn(move || {
+    ///         let hosts = vec!["alpha".to_string(), "beta".to_string()];
+    ///         sender.send(hosts.into_iter().map(|h| MenuItem::item(h.clone(), h)).collect());
+    ///     });
+    /// });
+    /// assert!(item.is_group());
+    /// ```
+    pub fn lazy(
+        name: impl Into<Cow<'static, str>>,
+        loader: impl Fn(ChildrenSender<T>) + Send + Sync + 'static,
+    ) -> Self {
+        let mut item = Self::group(name, vec![Self::loading()]);
+        item.lazy = Some(Box::new(Lazy {
+            loader: Box::new(loader),
+            cache: true,
+            loaded: false,
+            pending: None,
+        }));
+        item
+    }
+
+    /// whether lazy group keeps loaded children for later opens, default
+    /// is true. If false, children are loaded again on every open. It has
+    /// no effect on other items.
+    pub fn cache(mut self, cache: bool) -> Self {
+        if let Some(lazy) = self.lazy.as_mut() {
+            lazy.cache = cache;
+        }
+        self
+    }
+
+    /// placeholder row of lazy group being loaded
+    fn loading() -> Self {
+        Self::new("Loading…", None, vec![]).disabled(true)
+    }
+
+    /// set mnemonic explicitly, the first matching char in name is
+    /// underlined
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::item("Exit", "label_exit").mnemonic('x');
+    /// ```
+    pub fn mnemonic(mut self, c: char) -> Self {
+        let c = fold_case(c);
+        self.mnemonic = Some(c);
+        self.mnemonic_index = self.name.chars().position(|n| fold_case(n) == c);
+        self
+    }
+
+    #[cfg(test)]
+    fn with_highlight(mut self, highlight: bool) -> Self {
+        self.is_highlight = highlight;
+        self
+    }
+
+    /// set shortcut hint, which is rendered right aligned in drop down.
+    /// It is also the key [MenuState::dispatch_shortcut] matches against.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::item("Save", "label_save").shortcut("Ctrl+S");
+    /// ```
+    pub fn shortcut(mut self, shortcut: impl Into<Cow<'static, str>>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// set description, a longer help text for this item. App can show it
+    /// in status line with [MenuState::highlight_description], or let
+    /// [Menu::tooltip] draw it next to the drop down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::item("Save", "label_save")
+    ///     .description("Save the current buffer to disk");
+    /// assert_eq!(item.get_description(), Some("Save the current buffer to disk"));
+    /// ```
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// get description, see [MenuItem::description]
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// set static disabled state. Disabled item is skipped by navigation,
+    /// and can't be selected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::MenuItem;
+    ///
+    /// let item = MenuItem::item("Undo", "label_undo").disabled(true);
+    /// assert!(!item.is_enabled());
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// enable this item only when `pred` returns true for app context,
+    /// the context is supplied by [MenuState::update_context].
+    pub fn enabled_when<C: Any>(
+        mut self,
+        pred: impl Fn(&C) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.enabled_when = Some(Box::new(move |ctx: &dyn Any| {
+            ctx.downcast_ref::<C>().map(&pred)
+        }));
+        self
+    }
+
+    /// checked state, always false for non checkbox or radio item
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// update checked state, it has no effect on non checkbox or radio item.
+    /// NOTE: other radio items in the group are not touched, use
+    /// [MenuState::check_radio] to keep them exclusive
+    pub fn set_checked(&mut self, checked: bool) {
+        if matches!(self.kind, MenuItemKind::Checkbox | MenuItemKind::Radio(_)) {
+            self.checked = checked;
+        }
+    }
+
+    /// uncheck radio items in `group` recursively
+    fn uncheck_radio_group(&mut self, group: &str) {
+        for child in self.children.iter_mut() {
+            if matches!(&child.kind, MenuItemKind::Radio(g) if g == group) {
+                child.checked = false;
+            }
+            child.uncheck_radio_group(group);
+        }
+    }
 
-    /// highlight first child
-    fn highlight_first_child(&mut self) -> Option<()> {
-        if !self.children.is_empty() {
-            if let Some(it) = self.children.get_mut(0) {
-                it.is_highlight = true;
+    /// set checked state recursively for items whose data matches `pred`
+    fn set_checked_by(&mut self, pred: &impl Fn(&T) -> bool, checked: bool) -> bool {
+        let mut matched = false;
+        for child in self.children.iter_mut() {
+            if child.kind == MenuItemKind::Checkbox && child.data.as_ref().is_some_and(pred) {
+                child.checked = checked;
+                matched = true;
             }
-            Some(())
-        } else {
-            None
+            matched |= child.set_checked_by(pred, checked);
+        }
+        matched
+    }
+
+    /// whether this item is enabled, both static flag and context predicate
+    /// are considered
+    pub fn is_enabled(&self) -> bool {
+        !self.disabled && !self.context_disabled
+    }
+
+    /// evaluate context predicates recursively
+    fn update_context(&mut self, ctx: &dyn Any) {
+        if let Some(enabled) = self.enabled_when.as_ref().and_then(|pred| pred(ctx)) {
+            self.context_disabled = !enabled;
+        }
+        for child in self.children.iter_mut() {
+            child.update_context(ctx);
+        }
+    }
+
+    /// whether this item is group
+    pub fn is_group(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// get current item's name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// update name, `&` marks mnemonic like [MenuItem::item] does
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        let (name, mnemonic_index) = parse_mnemonic(name.into());
+        self.name = name;
+        if self.kind == MenuItemKind::Section {
+            return;
         }
+        self.mnemonic = mnemonic_index
+            .and_then(|idx| self.name.chars().nth(idx))
+            .map(fold_case);
+        self.mnemonic_index = mnemonic_index;
+    }
+
+    /// display width of name
+    fn name_width(&self) -> usize {
+        Span::raw(self.name()).width()
+    }
+
+    /// whether this item is only for decoration, e.g. separator
+    fn is_decoration(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Separator | MenuItemKind::Section)
+    }
+
+    /// whether this item can be highlighted, separators, sections and
+    /// disabled items can't
+    fn is_selectable(&self) -> bool {
+        !self.is_decoration() && self.is_enabled()
+    }
+
+    /// index of first child can be highlighted
+    fn first_selectable_index(&self) -> Option<usize> {
+        self.children.iter().position(|c| c.is_selectable())
+    }
+
+    /// index of last child can be highlighted
+    fn last_selectable_index(&self) -> Option<usize> {
+        self.children.iter().rposition(|c| c.is_selectable())
+    }
+
+    /// highlight first child
+    fn highlight_first_child(&mut self) -> Option<()> {
+        let idx = self.first_selectable_index()?;
+        self.children[idx].is_highlight = true;
+        Some(())
+    }
+
+    /// highlight last child
+    fn highlight_last_child(&mut self) -> Option<()> {
+        let idx = self.last_selectable_index()?;
+        self.children[idx].is_highlight = true;
+        Some(())
     }
 
     /// highlight prev item in this node
@@ -437,10 +2098,11 @@ impl<T> MenuItem<T> {
             return;
         };
 
-        let index_to_highlight = if current_index > 0 {
-            current_index - 1
-        } else {
-            0
+        // skip items can't be highlighted, if already the first, do nothing
+        let Some(index_to_highlight) =
+            (0..current_index).rfind(|&idx| self.children[idx].is_selectable())
+        else {
+            return;
         };
 
         self.children[current_index].clear_highlight();
@@ -455,11 +2117,72 @@ impl<T> MenuItem<T> {
             return;
         };
 
-        let index_to_highlight = (current_index + 1).min(self.children.len() - 1);
+        // skip items can't be highlighted, if already the last, do nothing
+        let Some(index_to_highlight) = (current_index + 1..self.children.len())
+            .find(|&idx| self.children[idx].is_selectable())
+        else {
+            return;
+        };
+
         self.children[current_index].clear_highlight();
         self.children[index_to_highlight].is_highlight = true;
     }
 
+    /// scroll drop downs along the highlight path, so highlighted items are
+    /// in view
+    fn scroll_to_highlight(&mut self) {
+        let Some(idx) = self.highlight_child_index() else {
+            return;
+        };
+        if self.viewport > 0 {
+            if idx < self.scroll_offset {
+                self.scroll_offset = idx;
+            } else if idx >= self.scroll_offset + self.viewport {
+                self.scroll_offset = idx + 1 - self.viewport;
+            }
+        }
+        self.children[idx].scroll_to_highlight();
+    }
+
+    /// scroll menu bar of `width` so highlighted child is in view, children
+    /// are drawn as " name " after one leading char.
+    ///
+    /// Return: true if children don't fit, one char at the end is then
+    ///         reserved for the overflow indicator
+    fn scroll_bar(&mut self, width: u16) -> bool {
+        // in usize, so many or long names don't overflow
+        let widths: Vec<usize> = self.children.iter().map(|c| c.name_width() + 2).collect();
+        if widths.iter().sum::<usize>() <= width.saturating_sub(1) as usize {
+            self.scroll_offset = 0;
+            return false;
+        }
+
+        let room = width.saturating_sub(2) as usize;
+        let fits = |range: &[usize]| range.iter().sum::<usize>() <= room;
+        let mut offset = self.scroll_offset.min(widths.len().saturating_sub(1));
+        if let Some(idx) = self.highlight_child_index() {
+            offset = offset.min(idx);
+            while offset < idx && !fits(&widths[offset..=idx]) {
+                offset += 1;
+            }
+        }
+        // give back space when the tail fits, e.g. terminal grows
+        while offset > 0 && fits(&widths[offset - 1..]) {
+            offset -= 1;
+        }
+        self.scroll_offset = offset;
+        true
+    }
+
+    /// scroll drop down by `delta` rows, keep in range
+    fn scroll(&mut self, delta: isize) {
+        let max_offset = self.children.len().saturating_sub(self.viewport);
+        self.scroll_offset = self
+            .scroll_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+    }
+
     /// return highlighted child index
     fn highlight_child_index(&self) -> Option<usize> {
         for (idx, child) in self.children.iter().enumerate() {
@@ -471,6 +2194,73 @@ impl<T> MenuItem<T> {
         None
     }
 
+    /// return the index path of highlighted items, not including self
+    fn highlight_path(&self) -> Vec<usize> {
+        let mut path = vec![];
+        let mut item = self;
+        while let Some(idx) = item.highlight_child_index() {
+            path.push(idx);
+            item = &item.children[idx];
+        }
+        path
+    }
+
+    /// index paths of groups whose drop down is open, shallow first
+    fn open_groups(&self) -> Vec<Vec<usize>> {
+        let path = self.highlight_path();
+        (1..=path.len())
+            .map(|len| path[..len].to_vec())
+            .filter(|group| {
+                self.item_at(group)
+                    .is_some_and(|item| item.is_group() && item.is_enabled())
+            })
+            .collect()
+    }
+
+    /// highlight the item at index path, other highlights are cleared
+    fn highlight_at(&mut self, path: &[usize]) {
+        self.children.iter_mut().for_each(|c| c.clear_highlight());
+
+        if let Some((&idx, rest)) = path.split_first() {
+            if let Some(child) = self.children.get_mut(idx) {
+                child.is_highlight = true;
+                child.highlight_at(rest);
+            }
+        }
+    }
+
+    /// return the index path of first item matches `pred`, depth first
+    fn find_path(&self, pred: &impl Fn(&Self) -> bool) -> Option<Vec<usize>> {
+        for (idx, child) in self.children.iter().enumerate() {
+            if pred(child) {
+                return Some(vec![idx]);
+            }
+            if let Some(mut path) = child.find_path(pred) {
+                path.insert(0, idx);
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    /// return the item at index path
+    fn item_at(&self, path: &[usize]) -> Option<&Self> {
+        let mut item = self;
+        for &idx in path {
+            item = item.children.get(idx)?;
+        }
+        Some(item)
+    }
+
+    /// mut version of item_at
+    fn item_at_mut(&mut self, path: &[usize]) -> Option<&mut Self> {
+        let mut item = self;
+        for &idx in path {
+            item = item.children.get_mut(idx)?;
+        }
+        Some(item)
+    }
+
     /// if any child highlighted, then return its reference
     fn highlight_child(&self) -> Option<&Self> {
         self.children.iter().filter(|i| i.is_highlight).nth(0)
@@ -481,6 +2271,79 @@ impl<T> MenuItem<T> {
         self.children.iter_mut().filter(|i| i.is_highlight).nth(0)
     }
 
+    /// highlight child nearest to `idx` can be highlighted, later one first
+    fn highlight_near(&mut self, idx: usize) {
+        let len = self.children.len();
+        if let Some(near) = (idx..len)
+            .chain((0..idx.min(len)).rev())
+            .find(|&i| self.children[i].is_selectable())
+        {
+            self.children[near].is_highlight = true;
+        }
+    }
+
+    /// highlight along `path` as deep as the items can be highlighted
+    fn highlight_valid(&mut self, path: &[usize]) {
+        if let Some((&idx, rest)) = path.split_first() {
+            if let Some(child) = self.children.get_mut(idx).filter(|c| c.is_selectable()) {
+                child.is_highlight = true;
+                child.highlight_valid(rest);
+            }
+        }
+    }
+
+    /// start loading children if this is a lazy group without valid
+    /// children
+    fn load_children(&mut self) {
+        let Some(lazy) = self.lazy.as_mut() else {
+            return;
+        };
+        if lazy.loaded || lazy.pending.is_some() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        lazy.pending = Some(Mutex::new(receiver));
+        self.children = vec![Self::loading()];
+        self.scroll_offset = 0;
+        (lazy.loader)(ChildrenSender(sender));
+        // loader may deliver right away
+        self.poll_children();
+    }
+
+    /// invalidate children of lazy group which doesn't cache
+    fn expire_children(&mut self) {
+        if let Some(lazy) = self.lazy.as_mut().filter(|lazy| !lazy.cache) {
+            lazy.loaded = false;
+        }
+    }
+
+    /// take delivered children of lazy groups in this tree
+    fn poll_children(&mut self) {
+        if let Some(lazy) = self.lazy.as_mut() {
+            if let Some(receiver) = lazy.pending.as_mut() {
+                let received = match receiver
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .try_recv()
+                {
+                    Ok(children) => Some(children),
+                    Err(TryRecvError::Empty) => None,
+                    // loader gave up
+                    Err(TryRecvError::Disconnected) => Some(vec![]),
+                };
+                if let Some(mut children) = received {
+                    children.iter_mut().for_each(|c| c.clear_highlight());
+                    self.children = children;
+                    self.scroll_offset = 0;
+                    lazy.loaded = true;
+                    lazy.pending = None;
+                }
+            }
+        }
+        self.children.iter_mut().for_each(|c| c.poll_children());
+    }
+
     /// clear is_highlight flag recursively.
     fn clear_highlight(&mut self) {
         self.is_highlight = false;
@@ -536,16 +2399,71 @@ impl<T> MenuItem<T> {
     }
 }
 
+/// strip the mnemonic marker from name, return the name and marked char index.
+/// `&&` is an escaped `&`, and `&` not followed by alphanumeric char is kept
+fn parse_mnemonic(name: Cow<'static, str>) -> (Cow<'static, str>, Option<usize>) {
+    if !name.contains('&') {
+        return (name, None);
+    }
+
+    let mut stripped = String::with_capacity(name.len());
+    let mut index = None;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.peek() {
+                Some('&') => {
+                    chars.next();
+                }
+                Some(next) if next.is_alphanumeric() && index.is_none() => {
+                    index = Some(stripped.chars().count());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        stripped.push(c);
+    }
+
+    (stripped.into(), index)
+}
+
+/// mnemonics are case insensitive
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// min gap between item name and its shortcut in drop down
+const SHORTCUT_GAP: u16 = 2;
+
+/// width or height in cells, capped at `u16::MAX` so many or long items
+/// don't overflow
+fn cells(len: usize) -> u16 {
+    u16::try_from(len).unwrap_or(u16::MAX)
+}
+
 /// Widget focus on display/render
 pub struct Menu<T> {
     /// style for default item style
     default_item_style: Style,
     /// style for highlighted item
     highlight_item_style: Style,
-    /// width for drop down panel
-    drop_down_width: u16,
+    /// style for disabled item
+    disabled_item_style: Style,
+    /// symbols for checked and unchecked checkbox
+    checkbox_symbols: [&'static str; 2],
+    /// symbols for checked and unchecked radio item
+    radio_symbols: [&'static str; 2],
     /// style for drop down panel
     drop_down_style: Style,
+    /// draw description of highlighted item in a tooltip
+    show_tooltip: bool,
+    /// style for tooltip
+    tooltip_style: Style,
+    /// where menu bar is
+    bar_anchor: BarAnchor,
+    /// how root items are laid out
+    orientation: Orientation,
     _priv: PhantomData<T>,
 }
 
@@ -554,8 +2472,14 @@ impl<T> Menu<T> {
         Self {
             highlight_item_style: Style::default().fg(Color::White).bg(Color::LightBlue),
             default_item_style: Style::default().fg(Color::White),
-            drop_down_width: 20,
+            disabled_item_style: Style::default().fg(Color::DarkGray),
+            checkbox_symbols: ["☑", "☐"],
+            radio_symbols: ["●", "○"],
             drop_down_style: Style::default().bg(Color::DarkGray),
+            show_tooltip: false,
+            tooltip_style: Style::default().fg(Color::Black).bg(Color::Gray),
+            bar_anchor: BarAnchor::Top,
+            orientation: Orientation::Horizontal,
             _priv: Default::default(),
         }
     }
@@ -572,9 +2496,50 @@ impl<T> Menu<T> {
         self
     }
 
-    /// update drop_down_width
-    pub fn dropdown_width(mut self, width: u16) -> Self {
-        self.drop_down_width = width;
+    /// update with disabled style
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_item_style = style;
+        self
+    }
+
+    /// update checkbox symbols, default is "☑" and "☐"
+    pub fn checkbox_symbols(mut self, checked: &'static str, unchecked: &'static str) -> Self {
+        self.checkbox_symbols = [checked, unchecked];
+        self
+    }
+
+    /// update radio symbols, default is "●" and "○"
+    pub fn radio_symbols(mut self, checked: &'static str, unchecked: &'static str) -> Self {
+        self.radio_symbols = [checked, unchecked];
+        self
+    }
+
+    /// symbol drawn in the gutter before item name
+    fn gutter_symbol(&self, item: &MenuItem<T>) -> Option<&'static str> {
+        match item.kind {
+            MenuItemKind::Checkbox if item.checked => Some(self.checkbox_symbols[0]),
+            MenuItemKind::Checkbox => Some(self.checkbox_symbols[1]),
+            MenuItemKind::Radio(_) if item.checked => Some(self.radio_symbols[0]),
+            MenuItemKind::Radio(_) => Some(self.radio_symbols[1]),
+            _ => None,
+        }
+    }
+
+    /// style to render item with
+    fn item_style(&self, item: &MenuItem<T>) -> Style {
+        if !item.is_enabled() {
+            self.disabled_item_style
+        } else if item.is_highlight {
+            self.highlight_item_style
+        } else {
+            self.default_item_style
+        }
+    }
+
+    /// drop down width is now computed from its items, and placement
+    /// doesn't need a fixed width, this has no effect
+    #[deprecated(note = "has no effect, drop down width is computed from its items")]
+    pub fn dropdown_width(self, _width: u16) -> Self {
         self
     }
 
@@ -584,6 +2549,310 @@ impl<T> Menu<T> {
         self
     }
 
+    /// draw description of highlighted item in a tooltip, default is false.
+    /// Tooltip is placed to the right of the highlighted item, or below
+    /// the deepest drop down when it would cover the next cascade.
+    pub fn tooltip(mut self, show: bool) -> Self {
+        self.show_tooltip = show;
+        self
+    }
+
+    /// update tooltip style
+    pub fn tooltip_style(mut self, style: Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
+    /// update bar anchor, default is [BarAnchor::Top]. With
+    /// [BarAnchor::Bottom] drop downs open above the bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{BarAnchor, Menu};
+    ///
+    /// let menu = Menu::<&'static str>::new().anchor(BarAnchor::Bottom);
+    /// ```
+    pub fn anchor(mut self, anchor: BarAnchor) -> Self {
+        self.bar_anchor = anchor;
+        self
+    }
+
+    /// update orientation, default is [Orientation::Horizontal]. With
+    /// [Orientation::Vertical] root items are drawn as a sidebar filling
+    /// the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{Menu, Orientation};
+    ///
+    /// let menu = Menu::<&'static str>::new().orientation(Orientation::Vertical);
+    /// ```
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// render root items top to bottom in area, and the fly out of
+    /// highlighted group
+    fn render_sidebar(&self, area: Rect, buf: &mut Buffer, state: &mut MenuState<T>) {
+        let area = area.clamp(*buf.area());
+        let mut areas = RenderedAreas::default();
+        areas.panels.push(area);
+        areas.dropdowns.push((area, vec![]));
+
+        let root = &mut state.root_item;
+        let visible = root.children.len().min(area.height as usize).max(1);
+        let first_render = root.viewport == 0;
+        root.viewport = visible;
+        if first_render {
+            root.scroll_to_highlight();
+        }
+        root.scroll(0);
+        let offset = root.scroll_offset;
+
+        buf.set_style(area, self.default_item_style);
+
+        let gutter_width = self.gutter_width(&root.children);
+        let mut open_group = None;
+        for (idx, item) in root.children.iter().enumerate().skip(offset).take(visible) {
+            let y = area.y + (idx - offset) as u16;
+
+            if item.kind == MenuItemKind::Separator {
+                let line = line::HORIZONTAL.repeat(area.width as usize);
+                buf.set_stringn(
+                    area.x,
+                    y,
+                    line,
+                    area.width as usize,
+                    self.default_item_style,
+                );
+                continue;
+            }
+            self.render_row(
+                item,
+                Position::new(area.x, y),
+                area.width,
+                gutter_width,
+                buf,
+            );
+            if item.kind == MenuItemKind::Section {
+                continue;
+            }
+            areas
+                .items
+                .push((Rect::new(area.x, y, area.width, 1), vec![idx]));
+
+            if item.is_highlight && item.is_group() && item.is_enabled() {
+                open_group = Some((idx, y));
+            }
+        }
+
+        // overflow indicators in the last column
+        let indicator_x = area.right().saturating_sub(1);
+        if offset > 0 {
+            buf.set_string(indicator_x, area.y, "▲", self.default_item_style);
+        }
+        if offset + visible < root.children.len() {
+            buf.set_string(
+                indicator_x,
+                area.bottom().saturating_sub(1),
+                "▼",
+                self.default_item_style,
+            );
+        }
+
+        if let Some((idx, row)) = open_group {
+            // sidebar has no blank margin column to share with the fly out
+            let parent = Rect {
+                width: area.width.saturating_add(1),
+                ..area
+            };
+            self.render_dropdown(
+                Anchor::Beside {
+                    parent,
+                    row,
+                    leftward: false,
+                },
+                *buf.area(),
+                &mut root.children[idx],
+                buf,
+                &[idx],
+                &mut areas,
+            );
+        }
+
+        state.mode = NavigationMode::Vertical;
+        if self.show_tooltip {
+            self.render_tooltip(state, buf, &areas);
+        }
+        state.areas = areas;
+    }
+
+    /// convert to [PopupMenu] opened at `position`, with this style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::layout::Position;
+    /// use tui_menu::Menu;
+    ///
+    /// let popup = Menu::<&'static str>::new().tooltip(true).popup(Position::new(10, 5));
+    /// ```
+    pub fn popup(self, position: Position) -> PopupMenu<T> {
+        PopupMenu {
+            menu: self,
+            position,
+        }
+    }
+
+    /// render description of highlighted item next to the panel it is in
+    fn render_tooltip(&self, state: &MenuState<T>, buf: &mut Buffer, areas: &RenderedAreas) {
+        let Some(item) = state.root_item.highlight() else {
+            return;
+        };
+        let Some(description) = item.get_description() else {
+            return;
+        };
+        let path = state.root_item.highlight_path();
+        let Some(row) = areas
+            .items
+            .iter()
+            .rev()
+            .find(|(_, p)| *p == path)
+            .map(|(area, _)| *area)
+        else {
+            return;
+        };
+
+        let width = cells(Span::raw(description).width()).saturating_add(4);
+        let height = 3;
+        let buf_area = *buf.area();
+
+        // the panel holding the item, the menu bar if it is a top level item
+        let panel = areas
+            .panels
+            .iter()
+            .rev()
+            .find(|panel| panel.contains(row.as_position()))
+            .copied()
+            .unwrap_or(row);
+        let cascade_open = item.is_group() && item.is_enabled();
+
+        let in_dropdown = path.len() > 1 || state.mode == NavigationMode::Vertical;
+        let position = if in_dropdown && !cascade_open {
+            // right of the item, or left of the panel if no room
+            let y = row.y.saturating_sub(1);
+            if panel.right().saturating_add(width) <= buf_area.right() {
+                Position::new(panel.right(), y)
+            } else {
+                Position::new(panel.x.saturating_sub(width), y)
+            }
+        } else {
+            // below the deepest drop down, so next cascade is not covered,
+            // or below the bar item if nothing opened. Above for bottom bar
+            let last = if cascade_open {
+                areas.panels.last().copied().unwrap_or(panel)
+            } else {
+                row
+            };
+            match self.bar_anchor {
+                BarAnchor::Top => Position::new(last.x, last.bottom()),
+                BarAnchor::Bottom => Position::new(last.x, last.y.saturating_sub(height)),
+            }
+        };
+
+        let area = Rect::new(position.x, position.y, width, height).clamp(buf_area);
+        Clear.render(area, buf);
+        Block::default()
+            .borders(Borders::ALL)
+            .style(self.tooltip_style)
+            .render(area, buf);
+        buf.set_stringn(
+            area.x + 2,
+            area.y + 1,
+            description,
+            area.width.saturating_sub(4) as usize,
+            self.tooltip_style,
+        );
+    }
+
+    /// spans of item name, mnemonic char is underlined
+    fn name_spans<'a>(&self, item: &'a MenuItem<T>, style: Style) -> Vec<Span<'a>> {
+        let name = item.name();
+        let Some((start, c)) = item
+            .mnemonic_index
+            .and_then(|idx| name.char_indices().nth(idx))
+        else {
+            return vec![Span::styled(name, style)];
+        };
+        let end = start + c.len_utf8();
+
+        vec![
+            Span::styled(&name[..start], style),
+            Span::styled(&name[start..end], style.add_modifier(Modifier::UNDERLINED)),
+            Span::styled(&name[end..], style),
+        ]
+    }
+
+    /// width of gutter where checkbox and radio symbols are drawn before
+    /// name, 0 if `items` has none
+    fn gutter_width(&self, items: &[MenuItem<T>]) -> u16 {
+        items
+            .iter()
+            .filter_map(|menu_item| self.gutter_symbol(menu_item))
+            .map(|symbol| cells(Span::raw(symbol).width()).saturating_add(1))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// render row of item `width` wide, shared by drop downs and sidebar.
+    /// Section is a dim title, others have gutter, name, right aligned
+    /// shortcut and `>` for group
+    fn render_row(
+        &self,
+        item: &MenuItem<T>,
+        position: Position,
+        width: u16,
+        gutter_width: u16,
+        buf: &mut Buffer,
+    ) {
+        if item.kind == MenuItemKind::Section {
+            let style = self.default_item_style.add_modifier(Modifier::DIM);
+            let line = Line::from(vec![Span::raw(" "), Span::raw(item.name())]).style(style);
+            buf.set_line(position.x, position.y, &line, width);
+            return;
+        }
+
+        let item_style = self.item_style(item);
+
+        // make style apply to whole line by padding name to whole line
+        let mut spans = vec![Span::styled(" ", item_style)];
+        if gutter_width > 0 {
+            let symbol = self.gutter_symbol(item).unwrap_or_default();
+            spans.push(Span::styled(
+                format!("{symbol: <width$}", width = gutter_width as usize),
+                item_style,
+            ));
+        }
+        spans.extend(self.name_spans(item, item_style));
+        let shortcut = item.shortcut.as_deref().unwrap_or_default();
+        let padding = (width as usize)
+            .saturating_sub(2)
+            .saturating_sub(gutter_width as usize)
+            .saturating_sub(item.name_width())
+            .saturating_sub(Span::raw(shortcut).width());
+        spans.push(Span::styled(" ".repeat(padding), item_style));
+        spans.push(Span::styled(shortcut, item_style));
+        spans.push(Span::styled(
+            if item.children.is_empty() { " " } else { ">" },
+            item_style,
+        ));
+
+        buf.set_line(position.x, position.y, &Line::from(spans), width);
+    }
+
     /// render an item group in drop down
     /* Each menu item is rendered like this
     .|.NameString.|.
@@ -591,43 +2860,60 @@ impl<T> Menu<T> {
     */
     fn render_dropdown(
         &self,
-        x: u16,
-        y: u16,
-        group: &[MenuItem<T>],
+        anchor: Anchor,
+        bounds: Rect,
+        group: &mut MenuItem<T>,
         buf: &mut Buffer,
-        dropdown_count_to_go: u16, // including current, it is not drawn yet
+        path: &[usize],
+        areas: &mut RenderedAreas,
     ) {
-        // Compute width of all menu items
-        let child_max_width = group
+        // Compute width of all menu items, shortcuts are in their own column
+        let name_max_width = group
+            .children
             .iter()
-            .map(|menu_item| Span::from(menu_item.name.clone()).width())
+            .map(|menu_item| menu_item.name_width())
             .max()
-            .unwrap_or(0) as u16;
+            .map_or(0, cells);
+        let shortcut_max_width = group
+            .children
+            .iter()
+            .filter_map(|menu_item| menu_item.shortcut.as_deref())
+            .map(|shortcut| Span::raw(shortcut).width())
+            .max()
+            .map(|width| cells(width).saturating_add(SHORTCUT_GAP));
+        let gutter_width = self.gutter_width(&group.children);
+        let child_max_width = gutter_width
+            .saturating_add(name_max_width)
+            .saturating_add(shortcut_max_width.unwrap_or(0));
 
         // Compute minimum size needed after border is added
         // Border is 3 chars wide and 1 char high, on both sides.
-        let min_drop_down_width: u16 = child_max_width + 3 + 3;
-        let min_drop_down_height: u16 = (group.len() as u16) + 1 + 1;
-
-        // prevent calculation issue if canvas is narrow
-        let drop_down_width = self.drop_down_width.min(buf.area.width);
+        let min_drop_down_width = child_max_width.saturating_add(3 + 3);
+        let min_drop_down_height = cells(group.children.len().saturating_add(1 + 1));
 
-        // calculate the maximum x, leaving enough space for deeper items
-        // drawing area:
-        // |  a |  b   |            c                |        d       |
-        // | .. |  me  |  child_1  |  child_of_child |  nothing here  |
-        // x_max is the x when d is 0
-        let b_plus_c = dropdown_count_to_go * drop_down_width;
-        let x_max = buf.area().right().saturating_sub(b_plus_c);
-
-        let x = x.min(x_max);
-
-        let area = Rect::new(x, y, min_drop_down_width, min_drop_down_height);
+        let area = anchor.place(min_drop_down_width, min_drop_down_height, bounds);
+        // no room for borders and a row, e.g. terminal of 2 rows
+        if area.height < 3 {
+            return;
+        }
+        let Rect { x, y, .. } = area;
 
-        // clamp to ensure we draw in areas
-        let area = area.clamp(*buf.area());
+        // rows that fit in placed area, the rest is scrolled
+        let visible = group
+            .children
+            .len()
+            .min(area.height.saturating_sub(2) as usize);
+        let first_render = group.viewport == 0;
+        group.viewport = visible;
+        if first_render {
+            group.scroll_to_highlight();
+        }
+        group.scroll(0);
+        let offset = group.scroll_offset;
 
         Clear.render(area, buf);
+        areas.panels.push(area);
+        areas.dropdowns.push((area, path.to_vec()));
 
         buf.set_style(area, self.default_item_style);
 
@@ -643,47 +2929,213 @@ impl<T> Menu<T> {
             buf,
         );
 
+        // overflow indicators in the border
+        let indicator_x = area.x + area.width / 2;
+        if offset > 0 {
+            buf.set_string(indicator_x, area.y, "▲", self.default_item_style);
+        }
+        if offset + visible < group.children.len() {
+            buf.set_string(
+                indicator_x,
+                area.bottom().saturating_sub(1),
+                "▼",
+                self.default_item_style,
+            );
+        }
+
         // Render menu items
         let mut active_group: Option<_> = None;
-        for (idx, item) in group.iter().enumerate() {
+        for (idx, item) in group.children.iter().enumerate().skip(offset).take(visible) {
             let item_x = x + 2;
-            let item_y = y + 1 + idx as u16;
+            let item_y = y + 1 + (idx - offset) as u16;
             let is_active = item.is_highlight;
 
-            let item_name = item.name();
+            if item.kind == MenuItemKind::Separator {
+                // join the line into the border, ├───┤
+                let border_width = area.width.saturating_sub(2);
+                let line = format!(
+                    "{}{}{}",
+                    line::VERTICAL_RIGHT,
+                    line::HORIZONTAL.repeat(border_width.saturating_sub(2) as usize),
+                    line::VERTICAL_LEFT
+                );
+                buf.set_stringn(
+                    area.x + 1,
+                    item_y,
+                    line,
+                    border_width as usize,
+                    self.default_item_style,
+                );
+                continue;
+            }
+            self.render_row(
+                item,
+                Position::new(item_x, item_y),
+                child_max_width.saturating_add(2),
+                gutter_width,
+                buf,
+            );
+            if item.kind == MenuItemKind::Section {
+                continue;
+            }
 
-            // make style apply to whole line by make name whole line
-            let mut item_name =
-                format!(" {: <width$} ", item_name, width = child_max_width as usize);
+            let item_path = [path, &[idx]].concat();
+            let item_area =
+                Rect::new(item_x, item_y, child_max_width.saturating_add(2), 1).intersection(area);
+            if !item_area.is_empty() {
+                areas.items.push((item_area, item_path.clone()));
+            }
 
-            if !item.children.is_empty() {
-                item_name.pop();
-                item_name.push('>');
+            if is_active && item.is_group() && item.is_enabled() {
+                active_group = Some((item_y, idx, item_path));
             }
+        }
 
-            buf.set_span(
-                item_x,
-                item_y,
-                &Span::styled(
-                    item_name,
-                    if is_active {
-                        self.highlight_item_style
-                    } else {
-                        self.default_item_style
-                    },
-                ),
-                child_max_width + 2,
+        // draw at the end to ensure its content above all items in current level
+        if let Some((row, idx, item_path)) = active_group {
+            self.render_dropdown(
+                Anchor::Beside {
+                    parent: area,
+                    row,
+                    leftward: matches!(anchor, Anchor::Beside { parent, .. } if area.x < parent.x),
+                },
+                bounds,
+                &mut group.children[idx],
+                buf,
+                &item_path,
+                areas,
             );
+        }
+    }
+}
 
-            if is_active && !item.children.is_empty() {
-                active_group = Some((item_x + child_max_width, item_y, item));
-            }
+/// Context menu without menu bar, renders root items of [MenuState] as a
+/// drop down at a position, e.g. where right click happened. Up and down
+/// move within a level, right and left enter and leave sub menus.
+///
+/// Nothing is drawn when state is not active, call
+/// [MenuState::activate_popup] to open it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::{buffer::Buffer, layout::{Position, Rect}, widgets::StatefulWidget};
+/// use tui_menu::{MenuItem, MenuState, PopupMenu};
+///
+/// let mut state = MenuState::<&'static str>::new(vec![
+///     MenuItem::item("Copy", "label_copy"),
+///     MenuItem::item("Paste", "label_paste"),
+/// ]);
+/// state.activate_popup();
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let mut buf = Buffer::empty(area);
+/// PopupMenu::new(Position::new(5, 2)).render(area, &mut buf, &mut state);
+///
+/// state.down();
+/// assert_eq!(state.highlight().unwrap().data, Some("label_paste"));
+/// ```
+pub struct PopupMenu<T> {
+    /// style of drop downs
+    menu: Menu<T>,
+    /// top left corner of first level drop down
+    position: Position,
+}
+
+impl<T> PopupMenu<T> {
+    /// create with default style, see [Menu::popup] to customize it
+    pub fn new(position: Position) -> Self {
+        Menu::new().popup(position)
+    }
+}
+
+impl<T: Clone> StatefulWidget for PopupMenu<T> {
+    type State = MenuState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.root_item.poll_children();
+        state.mode = NavigationMode::Vertical;
+        state.areas = RenderedAreas::default();
+        if !state.is_active() {
+            return;
         }
 
-        // draw at the end to ensure its content above all items in current level
-        if let Some((x, y, item)) = active_group {
-            self.render_dropdown(x, y, &item.children, buf, dropdown_count_to_go - 1);
+        // drop downs are shifted to stay in area
+        let bounds = area.clamp(*buf.area());
+        let mut areas = RenderedAreas::default();
+        self.menu.render_dropdown(
+            Anchor::Below(self.position),
+            bounds,
+            &mut state.root_item,
+            buf,
+            &[],
+            &mut areas,
+        );
+        if self.menu.show_tooltip {
+            self.menu.render_tooltip(state, buf, &areas);
         }
+        state.areas = areas;
+    }
+}
+
+/// Where a drop down opens from
+#[derive(Debug, Clone, Copy)]
+enum Anchor {
+    /// below menu bar item, top left corner at position
+    Below(Position),
+    /// above menu bar item, bottom left corner just above position
+    Above(Position),
+    /// beside the item at `row` of `parent` drop down, `leftward` if parent
+    /// itself opened to the left
+    Beside {
+        parent: Rect,
+        row: u16,
+        leftward: bool,
+    },
+}
+
+impl Anchor {
+    /// place drop down of `width` and `height` in `bounds`.
+    ///
+    /// Cascade keeps the direction of its parent, and opens to the right
+    /// initially. When it doesn't fit, it flips to the other side, or the
+    /// side with more room if neither fits. Its top is aligned with parent
+    /// row, or its bottom if there is not enough space below. Then it is
+    /// shifted to stay inside bounds.
+    fn place(self, width: u16, height: u16, bounds: Rect) -> Rect {
+        let (x, y) = match self {
+            Anchor::Below(position) => (position.x, position.y),
+            Anchor::Above(position) => (position.x, position.y.saturating_sub(height)),
+            Anchor::Beside {
+                parent,
+                row,
+                leftward,
+            } => {
+                // the blank margin columns of both panels overlap
+                let right = parent.right().saturating_sub(1);
+                let left = parent.x.saturating_add(1);
+                let fits_right = right.saturating_add(width) <= bounds.right();
+                let fits_left = left >= bounds.x.saturating_add(width);
+                let x = match (leftward, fits_left, fits_right) {
+                    (true, true, _) | (false, true, false) => left - width,
+                    (_, _, true) => right,
+                    _ if bounds.right().saturating_sub(right) >= left.saturating_sub(bounds.x) => {
+                        right
+                    }
+                    _ => bounds.x,
+                };
+                let y = if row.saturating_add(height) > bounds.bottom() {
+                    row.saturating_add(1).saturating_sub(height)
+                } else {
+                    row
+                };
+                (x, y)
+            }
+        };
+
+        let x = x.min(bounds.right().saturating_sub(width)).max(bounds.x);
+        let y = y.min(bounds.bottom().saturating_sub(height)).max(bounds.y);
+        Rect::new(x, y, width, height).intersection(bounds)
     }
 }
 
@@ -697,42 +3149,116 @@ impl<T: Clone> StatefulWidget for Menu<T> {
     type State = MenuState<T>;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.root_item.poll_children();
+        if self.orientation == Orientation::Vertical {
+            return self.render_sidebar(area, buf, state);
+        }
         let area = area.clamp(*buf.area());
 
         let mut spans = vec![];
         let mut x_pos = area.x;
         let y_pos = area.y;
 
-        let dropdown_count = state.dropdown_count();
+        let mut areas = RenderedAreas::default();
+        areas
+            .panels
+            .push(Rect::new(area.x, area.y, area.width, 1.min(area.height)));
 
-        // Skip top left char
-        spans.push(Span::raw(" ").style(self.default_item_style));
+        // items scrolled out are marked with « and », the top left char is
+        // reused for «
+        let overflow = state.root_item.scroll_bar(area.width);
+        let offset = state.root_item.scroll_offset;
+        let bar_width = if overflow {
+            area.width.saturating_sub(1)
+        } else {
+            area.width
+        };
+        let bar = Rect::new(area.x, y_pos, bar_width, 1.min(area.height));
+        spans.push(Span::raw(if offset > 0 { "«" } else { " " }).style(self.default_item_style));
 
-        for item in state.root_item.children.iter() {
+        let mut open_group = None;
+        for (idx, item) in state.root_item.children.iter().enumerate().skip(offset) {
             let is_highlight = item.is_highlight;
-            let item_style = if is_highlight {
-                self.highlight_item_style
+            let item_style = if item.kind == MenuItemKind::Section {
+                self.default_item_style.add_modifier(Modifier::DIM)
             } else {
-                self.default_item_style
+                self.item_style(item)
             };
             let has_children = !item.children.is_empty();
 
             let group_x_pos = x_pos;
-            let span = Span::styled(format!(" {} ", item.name()), item_style);
-            x_pos += span.width() as u16;
-            spans.push(span);
+            let item_width = cells(item.name_width().saturating_add(2));
+            x_pos = x_pos.saturating_add(item_width);
+
+            // item is drawn after the top left char
+            let item_area =
+                Rect::new(group_x_pos.saturating_add(1), y_pos, item_width, 1).intersection(bar);
+            if !item_area.is_empty() && !item.is_decoration() {
+                areas.items.push((item_area, vec![idx]));
+            }
+            spans.push(Span::styled(" ", item_style));
+            spans.extend(self.name_spans(item, item_style));
+            spans.push(Span::styled(" ", item_style));
 
-            if has_children && is_highlight {
-                self.render_dropdown(group_x_pos, y_pos + 1, &item.children, buf, dropdown_count);
+            if has_children && is_highlight && item.is_enabled() {
+                open_group = Some((idx, group_x_pos));
             }
         }
-        buf.set_line(area.x, area.y, &Line::from(spans), area.width);
+        buf.set_line(area.x, area.y, &Line::from(spans), bar_width);
+        if overflow && x_pos.saturating_add(1) > bar.right() {
+            buf.set_string(bar.right(), y_pos, "»", self.default_item_style);
+        }
+
+        if let Some((idx, group_x_pos)) = open_group {
+            // drop downs may cover content beside menu, but not menu bar
+            let buf_area = *buf.area();
+            let (anchor, bounds) = match self.bar_anchor {
+                BarAnchor::Top => (
+                    Anchor::Below(Position::new(group_x_pos, y_pos + 1)),
+                    Rect {
+                        y: y_pos + 1,
+                        height: buf_area.bottom().saturating_sub(y_pos + 1),
+                        ..buf_area
+                    },
+                ),
+                BarAnchor::Bottom => (
+                    Anchor::Above(Position::new(group_x_pos, y_pos)),
+                    Rect {
+                        height: y_pos.saturating_sub(buf_area.y),
+                        ..buf_area
+                    },
+                ),
+            };
+            self.render_dropdown(
+                anchor,
+                bounds,
+                &mut state.root_item.children[idx],
+                buf,
+                &[idx],
+                &mut areas,
+            );
+        }
+        if self.show_tooltip {
+            self.render_tooltip(state, buf, &areas);
+        }
+        state.areas = areas;
+        state.mode = NavigationMode::Bar(self.bar_anchor);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::MenuState;
+    use crate::{
+        BarAnchor, Menu, MenuEvent, MenuPath, MenuState, MouseAction, Orientation, PopupMenu,
+    };
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::{Position, Rect},
+        style::{Color, Modifier},
+        widgets::StatefulWidget,
+    };
+
+    use std::time::{Duration, Instant};
 
     type MenuItem = super::MenuItem<i32>;
 
@@ -779,85 +3305,1019 @@ mod tests {
     }
 
     #[test]
-    fn test_dropdown_count() {
-        {
-            // only item in menu bar
-            let menu_state = MenuState::new(vec![MenuItem::item("item1", 0)]);
-            assert_eq!(menu_state.dropdown_count(), 0);
+    fn test_handle_mouse() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::group(
+                "group1",
+                vec![MenuItem::item("item1", 1), MenuItem::item("item2", 2)],
+            ),
+            MenuItem::item("item3", 3),
+        ]);
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        let render = |state: &mut MenuState<i32>, buf: &mut Buffer| {
+            Menu::new().render(area, buf, state);
+        };
+
+        // " group1  item3"
+        render(&mut menu_state, &mut buf);
+        assert!(menu_state.handle_mouse(3, 0, MouseAction::Click));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0]);
+
+        // dropdown border at row 1, items start from row 2
+        render(&mut menu_state, &mut buf);
+        assert!(menu_state.handle_mouse(4, 3, MouseAction::Hover));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+
+        render(&mut menu_state, &mut buf);
+        assert!(menu_state.handle_mouse(4, 2, MouseAction::Click));
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+
+        // click outside resets menu, and is not consumed
+        render(&mut menu_state, &mut buf);
+        assert!(!menu_state.handle_mouse(30, 8, MouseAction::Click));
+        assert!(!menu_state.is_active());
+
+        // hover does nothing when menu is not active
+        render(&mut menu_state, &mut buf);
+        assert!(menu_state.handle_mouse(3, 0, MouseAction::Hover));
+        assert!(!menu_state.is_active());
+    }
+
+    #[test]
+    fn test_parse_mnemonic() {
+        let item = MenuItem::item("&Open", 0);
+        assert_eq!(item.name(), "Open");
+        assert_eq!(item.mnemonic, Some('o'));
+        assert_eq!(item.mnemonic_index, Some(0));
+
+        let item = MenuItem::item("Find && &Replace", 0);
+        assert_eq!(item.name(), "Find & Replace");
+        assert_eq!(item.mnemonic, Some('r'));
+        assert_eq!(item.mnemonic_index, Some(7));
+
+        let item = MenuItem::item("Find & Replace", 0);
+        assert_eq!(item.name(), "Find & Replace");
+        assert_eq!(item.mnemonic, None);
+
+        let item = MenuItem::item("Exit", 0).mnemonic('X');
+        assert_eq!(item.mnemonic, Some('x'));
+        assert_eq!(item.mnemonic_index, Some(1));
+    }
+
+    #[test]
+    fn test_handle_char() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "&File",
+            vec![
+                MenuItem::item("&Save", 1),
+                MenuItem::item("Save &as", 2),
+                MenuItem::item("&Abort", 3),
+            ],
+        )]);
+
+        assert!(!menu_state.handle_char('f'));
+        assert!(menu_state.handle_alt_char('F'));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 0]);
+
+        // ambiguous mnemonic cycles without selecting
+        assert!(menu_state.handle_char('a'));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+        assert!(menu_state.handle_char('a'));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 2]);
+        assert!(menu_state.handle_char('a'));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+        assert!(!menu_state
+            .drain_events()
+            .any(|e| matches!(e, MenuEvent::Selected(_))));
+
+        assert!(!menu_state.handle_char('z'));
+        assert!(menu_state.handle_char('s'));
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+    }
+
+    #[test]
+    fn test_render_mnemonic() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "&File",
+            vec![MenuItem::item("E&xit", 0)],
+        )]);
+        menu_state.activate();
+
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+
+        // " File " in bar, " Exit " in dropdown
+        assert!(buf[(2, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(!buf[(3, 0)].modifier.contains(Modifier::UNDERLINED));
+        assert!(buf[(4, 2)].modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(buf[(4, 2)].symbol(), "x");
+    }
+
+    #[test]
+    fn test_shortcut() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "File",
+            vec![
+                MenuItem::item("Save", 1).shortcut("Ctrl+S"),
+                MenuItem::item("Save as", 2),
+            ],
+        )]);
+
+        assert!(!menu_state.dispatch_shortcut("Ctrl+A"));
+        assert!(menu_state.dispatch_shortcut("Ctrl+S"));
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+        assert!(!menu_state.is_active());
+
+        menu_state.activate();
+        let area = Rect::new(0, 0, 30, 5);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+
+        let row = |y: u16| (2..19).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(2), " Save     Ctrl+S ");
+        assert_eq!(row(3), " Save as         ");
+    }
+
+    #[test]
+    fn test_separator_and_section() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "File",
+            vec![
+                MenuItem::section("Document"),
+                MenuItem::item("Save", 1),
+                MenuItem::separator(),
+                MenuItem::item("Exit", 2),
+            ],
+        )]);
+
+        menu_state.activate();
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 3]);
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 3]);
+        menu_state.up();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+        menu_state.up();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0]);
+        menu_state.down();
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+
+        // border from x = 1 to x = 12
+        let row = |y: u16| (1..13).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(4), "├──────────┤");
+        assert!(buf[(3, 2)].modifier.contains(Modifier::DIM));
+
+        // rows can't be highlighted are not clickable
+        assert!(menu_state.handle_mouse(4, 4, MouseAction::Hover));
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_disabled() {
+        struct Clipboard(bool);
+
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "Edit",
+            vec![
+                MenuItem::item("Undo", 1).disabled(true),
+                MenuItem::item("Copy", 2),
+                MenuItem::item("Paste", 3).enabled_when(|c: &Clipboard| c.0),
+            ],
+        )]);
+
+        menu_state.update_context(&Clipboard(false));
+        menu_state.activate();
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+
+        // context of other type is ignored
+        menu_state.update_context(&0);
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 1]);
+
+        menu_state.update_context(&Clipboard(true));
+        menu_state.down();
+        assert_eq!(menu_state.root_item.highlight_path(), vec![0, 2]);
+
+        // highlighted item becomes disabled
+        menu_state.update_context(&Clipboard(false));
+        menu_state.select();
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::DisabledActivated(3))
+        ));
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+        assert_eq!(buf[(3, 2)].fg, Color::DarkGray);
+        assert_eq!(buf[(3, 3)].fg, Color::White);
+
+        assert!(menu_state.handle_mouse(3, 2, MouseAction::Click));
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::DisabledActivated(1))
+        ));
+    }
+
+    #[test]
+    fn test_checkbox() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "View",
+            vec![
+                MenuItem::checkbox("Wrap", 1, false),
+                MenuItem::item("Zoom", 2),
+            ],
+        )]);
+
+        menu_state.activate();
+        menu_state.down();
+        menu_state.select();
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Toggled(1, true))
+        ));
+        assert_eq!(menu_state.is_checked(|data| *data == 1), Some(true));
+        assert_eq!(menu_state.is_checked(|data| *data == 2), None);
+
+        assert!(menu_state.set_checked(|data| *data == 1, false));
+        assert!(!menu_state.set_checked(|data| *data == 2, false));
+        assert_eq!(menu_state.is_checked(|data| *data == 1), Some(false));
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        Menu::new()
+            .checkbox_symbols("[x]", "[ ]")
+            .render(area, &mut buf, &mut menu_state);
+
+        let row = |y: u16| (2..13).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(2), " [ ] Wrap │");
+        assert_eq!(row(3), "     Zoom │");
+    }
+
+    #[test]
+    fn test_radio() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "Theme",
+            vec![
+                MenuItem::radio("Light", "theme", 1, true),
+                MenuItem::radio("Dark", "theme", 2, false),
+                MenuItem::radio("Compact", "density", 3, true),
+            ],
+        )]);
+
+        menu_state.activate();
+        menu_state.down();
+        menu_state.down();
+        menu_state.select();
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Selected(2))
+        ));
+        assert_eq!(menu_state.is_checked(|data| *data == 1), Some(false));
+        assert_eq!(menu_state.is_checked(|data| *data == 2), Some(true));
+        // other group is not affected
+        assert_eq!(menu_state.is_checked(|data| *data == 3), Some(true));
+
+        assert!(menu_state.check_radio(|data| *data == 1));
+        assert!(!menu_state.check_radio(|data| *data == 4));
+        assert_eq!(menu_state.is_checked(|data| *data == 1), Some(true));
+        assert_eq!(menu_state.is_checked(|data| *data == 2), Some(false));
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+        assert_eq!(buf[(3, 2)].symbol(), "●");
+        assert_eq!(buf[(3, 3)].symbol(), "○");
+    }
+
+    #[test]
+    fn test_navigation_events() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::group("group1", vec![MenuItem::item("item1", 1)]),
+            MenuItem::item("item2", 2),
+        ]);
+        let drain = |state: &mut MenuState<i32>| {
+            state
+                .drain_events()
+                .map(|e| format!("{e:?}"))
+                .collect::<Vec<_>>()
+        };
+
+        menu_state.activate();
+        assert_eq!(drain(&mut menu_state), vec!["GroupOpened(MenuPath([0]))"]);
+
+        menu_state.down();
+        assert_eq!(drain(&mut menu_state), vec!["Highlighted(1)"]);
+
+        menu_state.right();
+        assert_eq!(
+            drain(&mut menu_state),
+            vec!["GroupClosed(MenuPath([0]))", "Highlighted(2)"]
+        );
+
+        menu_state.reset();
+        assert_eq!(drain(&mut menu_state), vec!["Cancelled"]);
+
+        // reset after selection is not cancel
+        menu_state.activate();
+        menu_state.down();
+        menu_state.select();
+        menu_state.reset();
+        assert_eq!(
+            drain(&mut menu_state),
+            vec![
+                "GroupOpened(MenuPath([0]))",
+                "Highlighted(1)",
+                "Selected(1)",
+                "GroupClosed(MenuPath([0]))"
+            ]
+        );
+
+        // nothing happens when already closed
+        menu_state.reset();
+        assert!(drain(&mut menu_state).is_empty());
+
+        // click highlights and selects in one step
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+        assert!(menu_state.handle_mouse(11, 0, MouseAction::Click));
+        menu_state.reset();
+        assert_eq!(
+            drain(&mut menu_state),
+            vec!["Highlighted(2)", "Selected(2)"]
+        );
+    }
+
+    #[test]
+    fn test_description_tooltip() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "File",
+            vec![
+                MenuItem::item("Save", 1).description("save it"),
+                MenuItem::group("Recent", vec![MenuItem::item("a", 2)]).description("recent"),
+            ],
+        )]);
+        menu_state.activate();
+        menu_state.down();
+        assert_eq!(menu_state.highlight_description(), Some("save it"));
+
+        let area = Rect::new(0, 0, 40, 10);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            Menu::new().tooltip(true).render(area, &mut buf, state);
+            buf
+        };
+
+        // to the right of the drop down, text aligned with the item
+        let buf = render(&mut menu_state);
+        assert_eq!(buf[(14, 2)].symbol(), "s");
+        assert_eq!(buf[(12, 1)].symbol(), "┌");
+
+        // below the open cascade, so it is not covered
+        menu_state.down();
+        let buf = render(&mut menu_state);
+        assert_eq!(buf[(14, 4)].symbol(), "a");
+        assert_eq!(buf[(13, 7)].symbol(), "r");
+    }
+
+    #[test]
+    fn test_scroll() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "Group",
+            (0..10).map(|i| MenuItem::item(i.to_string(), i)).collect(),
+        )]);
+        menu_state.activate();
+        menu_state.down();
+
+        let area = Rect::new(0, 0, 20, 6);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            Menu::new().render(area, &mut buf, state);
+            buf
+        };
+        let row = |buf: &Buffer, y: u16| -> String {
+            (0..area.width).map(|x| buf[(x, y)].symbol()).collect()
+        };
+
+        // 3 rows fit between borders
+        let buf = render(&mut menu_state);
+        assert_eq!(row(&buf, 2).trim_end(), " │ 0 │");
+        assert_eq!(row(&buf, 4).trim_end(), " │ 2 │");
+        assert_eq!(row(&buf, 5).trim_end(), " └─▼─┘");
+
+        // highlight is kept in view
+        for _ in 0..3 {
+            menu_state.down();
         }
+        let buf = render(&mut menu_state);
+        assert_eq!(row(&buf, 1).trim_end(), " ┌─▲─┐");
+        assert_eq!(row(&buf, 4).trim_end(), " │ 3 │");
+        assert_eq!(buf[(3, 4)].bg, Color::LightBlue);
 
-        {
-            // group in menu bar,
-            let menu_state = MenuState::new(vec![MenuItem::group(
-                "menu bar",
-                vec![MenuItem::item("item layer 1", 0)],
-            )
-            .with_highlight(true)]);
-            assert_eq!(menu_state.dropdown_count(), 1);
+        // wheel scrolls without moving highlight, clamped to range
+        assert!(menu_state.handle_mouse(3, 3, MouseAction::ScrollUp));
+        let buf = render(&mut menu_state);
+        assert_eq!(row(&buf, 2).trim_end(), " │ 0 │");
+        assert_eq!(menu_state.highlight().unwrap().data, Some(3));
+        for _ in 0..20 {
+            menu_state.handle_mouse(3, 3, MouseAction::ScrollDown);
         }
+        let buf = render(&mut menu_state);
+        assert_eq!(row(&buf, 4).trim_end(), " │ 9 │");
+        assert_eq!(row(&buf, 5).trim_end(), " └───┘");
 
-        {
-            // group in menu bar,
-            let menu_state = MenuState::new(vec![MenuItem::group(
-                "menu bar 1",
-                vec![
-                    MenuItem::group("dropdown 1", vec![MenuItem::item("item layer 2", 0)])
-                        .with_highlight(true),
-                    MenuItem::item("item layer 1", 0),
-                ],
-            )
-            .with_highlight(true)]);
-            assert_eq!(menu_state.dropdown_count(), 2);
+        // too short for a drop down, it is not drawn
+        for height in [1, 2] {
+            let area = Rect::new(0, 0, 20, height);
+            let mut buf = Buffer::empty(area);
+            Menu::new().render(area, &mut buf, &mut menu_state);
+            assert_eq!(buf[(2, 0)].symbol(), "G");
+            PopupMenu::new(Position::new(0, 0)).render(area, &mut buf, &mut menu_state);
         }
+    }
 
-        {
-            // *menu bar 1
-            // *dropdown 1   >  item layer 2
-            // item layer 1    group layer 2 >
-            let menu_state = MenuState::new(vec![MenuItem::group(
-                "menu bar 1",
+    #[test]
+    fn test_bar_overflow() {
+        let mut menu_state = MenuState::new(
+            ["AAA", "BBB", "CCC", "DDD"]
+                .iter()
+                .enumerate()
+                .map(|(i, name)| MenuItem::item(*name, i as i32))
+                .collect(),
+        );
+        let area = Rect::new(0, 0, 13, 1);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            Menu::new().render(area, &mut buf, state);
+            (0..area.width)
+                .map(|x| buf[(x, 0)].symbol().to_string())
+                .collect::<String>()
+        };
+
+        assert_eq!(render(&mut menu_state), "  AAA  BBB  »");
+
+        // bar follows highlight
+        menu_state.activate();
+        for _ in 0..3 {
+            menu_state.right();
+        }
+        assert_eq!(render(&mut menu_state), "« CCC  DDD   ");
+        // hidden items are not hit by mouse
+        assert!(menu_state.handle_mouse(3, 0, MouseAction::Click));
+        assert!(matches!(
+            menu_state.drain_events().last(),
+            Some(MenuEvent::Selected(2))
+        ));
+
+        menu_state.left();
+        assert_eq!(render(&mut menu_state), "« BBB  CCC  »");
+
+        // all items fit in wide area
+        let area = Rect::new(0, 0, 30, 1);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(2, 0)].symbol(), "A");
+
+        // total width beyond u16 doesn't overflow
+        let long = "x".repeat(30_000);
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::item(long.clone(), 0),
+            MenuItem::item(long.clone(), 1),
+            MenuItem::item(long, 2),
+            MenuItem::item("Short", 3),
+        ]);
+        menu_state.activate();
+        for _ in 0..3 {
+            menu_state.right();
+        }
+        assert_eq!(render(&mut menu_state), "« Short      ");
+    }
+
+    #[test]
+    fn test_dropdown_overflow() {
+        // size of items beyond u16 doesn't overflow, drop down is clamped
+        let mut children: Vec<_> = (0..70_000).map(|i| MenuItem::item("Item", i)).collect();
+        children.push(MenuItem::checkbox("x".repeat(65_530), -1, true).shortcut("Ctrl+X"));
+        let mut menu_state = MenuState::new(vec![MenuItem::group("File", children)]);
+        menu_state.activate();
+        menu_state.down();
+
+        let area = Rect::new(0, 0, 20, 5);
+        let mut buf = Buffer::empty(area);
+        Menu::new().render(area, &mut buf, &mut menu_state);
+        let row = (0..area.width)
+            .map(|x| buf[(x, 2)].symbol())
+            .collect::<String>();
+        assert_eq!(row, " │   Item           ");
+    }
+
+    #[test]
+    fn test_placement() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::item("Long name", 0),
+            MenuItem::group(
+                "G",
                 vec![
-                    MenuItem::group(
-                        "dropdown 1",
-                        vec![
-                            MenuItem::item("item layer 2", 0),
-                            MenuItem::group(
-                                "group layer 2",
-                                vec![MenuItem::item("item layer 3", 0)],
-                            ),
-                        ],
-                    )
-                    .with_highlight(true),
-                    MenuItem::item("item layer 1", 0),
+                    MenuItem::item("a", 1),
+                    MenuItem::item("b", 2),
+                    MenuItem::group("Sub", (3..7).map(|i| MenuItem::item("x", i)).collect()),
                 ],
-            )
-            .with_highlight(true)]);
-            assert_eq!(menu_state.dropdown_count(), 2);
+            ),
+        ]);
+        menu_state.root_item.highlight_at(&[1, 2, 0]);
+
+        let render = |state: &mut MenuState<i32>, width: u16| {
+            let area = Rect::new(0, 0, width, 7);
+            let mut buf = Buffer::empty(area);
+            Menu::new().render(area, &mut buf, state);
+            (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // opens to the right, shifted up to stay above bottom edge
+        assert_eq!(
+            render(&mut menu_state, 30),
+            vec![
+                "  Long name  G",
+                "            ┌─────┐ ┌───┐",
+                "            │ a   │ │ x │",
+                "            │ b   │ │ x │",
+                "            │ Sub>│ │ x │",
+                "            └─────┘ │ x │",
+                "                    └───┘",
+            ]
+        );
+
+        // flips to the left after resize
+        assert_eq!(
+            render(&mut menu_state, 22),
+            vec![
+                "  Long name  G",
+                "      ┌───┐ ┌─────┐",
+                "      │ x │ │ a   │",
+                "      │ x │ │ b   │",
+                "      │ x │ │ Sub>│",
+                "      │ x │ └─────┘",
+                "      └───┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dropdown_width() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "File",
+            vec![
+                MenuItem::item("Open", 0).shortcut("Ctrl+O"),
+                MenuItem::checkbox("Wrap", 1, true),
+                MenuItem::item("Save as", 2),
+            ],
+        )]);
+        menu_state.activate();
+        menu_state.down();
+        let area = Rect::new(0, 0, 30, 6);
+
+        // gutter, longest name and shortcut column, regardless of the
+        // deprecated fixed width
+        #[allow(deprecated)]
+        for menu in [Menu::new(), Menu::new().dropdown_width(5)] {
+            let mut buf = Buffer::empty(area);
+            menu.render(area, &mut buf, &mut menu_state);
+            let rows = (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                rows,
+                vec![
+                    "  File",
+                    " ┌───────────────────┐",
+                    " │   Open     Ctrl+O │",
+                    " │ ☑ Wrap            │",
+                    " │   Save as         │",
+                    " └───────────────────┘",
+                ]
+            );
         }
+    }
 
-        {
-            // *menu bar 1
-            // *dropdown 1   >  *item layer 2
-            // item layer 1    group layer 2 > item layer 3
-            let menu_state = MenuState::new(vec![MenuItem::group(
-                "menu bar 1",
+    #[test]
+    fn test_bottom_anchor() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "Group",
+            vec![
+                MenuItem::item("a", 0),
+                MenuItem::item("b", 1),
+                MenuItem::item("c", 2),
+            ],
+        )]);
+        let area = Rect::new(0, 5, 20, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+        Menu::new()
+            .anchor(BarAnchor::Bottom)
+            .render(area, &mut buf, &mut menu_state);
+
+        // up opens the drop down at the item next to bar
+        menu_state.activate();
+        menu_state.up();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(2));
+        menu_state.up();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(1));
+        menu_state.down();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().name(), "Group");
+        menu_state.up();
+
+        Menu::new()
+            .anchor(BarAnchor::Bottom)
+            .render(area, &mut buf, &mut menu_state);
+        let rows: Vec<String> = (0..6)
+            .map(|y| {
+                (0..20)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![" ┌───┐", " │ a │", " │ b │", " │ c │", " └───┘", "  Group",]
+        );
+    }
+
+    #[test]
+    fn test_popup() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::item("Copy", 0),
+            MenuItem::group("More", vec![MenuItem::item("a", 1), MenuItem::item("b", 2)]),
+        ]);
+        let area = Rect::new(0, 0, 30, 8);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            PopupMenu::new(Position::new(3, 2)).render(area, &mut buf, state);
+            (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // navigation is vertical before first render
+        menu_state.activate_popup();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().name(), "More");
+        menu_state.reset();
+
+        // nothing drawn until activated
+        assert!(render(&mut menu_state).iter().all(|row| row.is_empty()));
+
+        menu_state.activate_popup();
+        menu_state.down();
+        menu_state.right();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(1));
+        menu_state.down();
+        assert_eq!(
+            render(&mut menu_state),
+            vec![
+                "",
+                "",
+                "    ┌──────┐",
+                "    │ Copy │",
+                "    │ More>│ ┌───┐",
+                "    └──────┘ │ a │",
+                "             │ b │",
+                "             └───┘",
+            ]
+        );
+
+        // left leaves sub menu, but not the first level
+        menu_state.left();
+        menu_state.left();
+        assert_eq!(menu_state.highlight().unwrap().name(), "More");
+
+        // click on hovered group opens it, instead of closing popup
+        render(&mut menu_state);
+        assert!(menu_state.handle_mouse(6, 4, MouseAction::Hover));
+        assert!(menu_state.handle_mouse(6, 4, MouseAction::Click));
+        assert!(menu_state.is_active());
+        assert_eq!(menu_state.highlight().unwrap().data, Some(1));
+
+        // click outside closes it
+        assert!(!menu_state.handle_mouse(0, 0, MouseAction::Click));
+        assert!(!menu_state.is_active());
+    }
+
+    #[test]
+    fn test_sidebar() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::item("Home", 0),
+            MenuItem::group(
+                "Files",
+                vec![MenuItem::item("a", 1), MenuItem::item("b", 2)],
+            ),
+            MenuItem::separator(),
+            MenuItem::item("Quit", 3),
+        ]);
+        let area = Rect::new(0, 0, 20, 6);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            Menu::new().orientation(Orientation::Vertical).render(
+                Rect::new(0, 0, 8, 6),
+                &mut buf,
+                state,
+            );
+            (0..area.height)
+                .map(|y| {
+                    (0..area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            render(&mut menu_state),
+            vec![" Home", " Files >", "────────", " Quit", "", ""]
+        );
+
+        // up and down move between root items, right opens fly out
+        menu_state.activate();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().name(), "Files");
+        menu_state.right();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(2));
+        assert_eq!(
+            render(&mut menu_state),
+            vec![
+                " Home",
+                " Files > ┌───┐",
+                "──────── │ a │",
+                " Quit    │ b │",
+                "         └───┘",
+                ""
+            ]
+        );
+        menu_state.left();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().name(), "Quit");
+
+        // root rows have checkbox gutter and shortcut column of drop downs
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::checkbox("Wrap", 0, true),
+            MenuItem::item("Quit", 1).shortcut("^Q"),
+        ]);
+        let area = Rect::new(0, 0, 12, 2);
+        let mut buf = Buffer::empty(area);
+        Menu::new()
+            .orientation(Orientation::Vertical)
+            .render(area, &mut buf, &mut menu_state);
+        let rows = (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec![" ☑ Wrap", "   Quit  ^Q"]);
+    }
+
+    #[test]
+    fn test_type_ahead() {
+        let mut menu_state = MenuState::new(vec![MenuItem::group(
+            "Open recent",
+            vec![
+                MenuItem::item("file_1", 0),
+                MenuItem::item("file_2", 1),
+                MenuItem::item("Folder", 2),
+                MenuItem::item("zed", 3),
+            ],
+        )]);
+        menu_state.activate();
+        menu_state.down();
+
+        let start = Instant::now();
+        let highlight = |state: &MenuState<i32>| state.highlight().unwrap().data;
+
+        // same letter cycles, wrapping around
+        for expected in [1, 2, 0] {
+            assert!(menu_state.type_ahead('f', start));
+            assert_eq!(highlight(&menu_state), Some(expected));
+        }
+
+        // buffer resets after timeout
+        let later = start + Duration::from_secs(2);
+        assert!(menu_state.type_ahead('z', later));
+        assert_eq!(highlight(&menu_state), Some(3));
+
+        let later = later + Duration::from_secs(2);
+        assert!(menu_state.type_ahead('F', later));
+        assert!(menu_state.type_ahead('o', later));
+        assert_eq!(highlight(&menu_state), Some(2));
+        assert!(!menu_state.type_ahead('x', later));
+        assert_eq!(highlight(&menu_state), Some(2));
+    }
+
+    #[test]
+    fn test_menu_path() {
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::item("Foo", 0),
+            MenuItem::group(
+                "File",
                 vec![
-                    MenuItem::group(
-                        "dropdown 1",
-                        vec![
-                            MenuItem::item("item layer 2", 0).with_highlight(true),
-                            MenuItem::group(
-                                "group layer 2",
-                                vec![MenuItem::item("item layer 3", 0)],
-                            ),
-                        ],
-                    )
-                    .with_highlight(true),
-                    MenuItem::item("item layer 1", 0),
+                    MenuItem::item("Disabled", 1).disabled(true),
+                    MenuItem::group("Recent", vec![MenuItem::item("a", 2)]),
                 ],
-            )
-            .with_highlight(true)]);
-            assert_eq!(menu_state.dropdown_count(), 3);
-        }
+            ),
+        ]);
+
+        let path = menu_state.find(|data| *data == 2).unwrap();
+        assert_eq!(path, MenuPath::new([1, 1, 0]));
+        assert_eq!(
+            menu_state.find_by_names(&["File", "Recent", "a"]),
+            Some(path.clone())
+        );
+        assert_eq!(menu_state.find_by_names(&["File", "a"]), None);
+
+        // groups along the way are opened
+        assert!(menu_state.set_highlight_path(&path));
+        assert_eq!(menu_state.highlight_path(), path);
+        let events: Vec<_> = menu_state
+            .drain_events()
+            .map(|e| format!("{e:?}"))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                "GroupOpened(MenuPath([1]))",
+                "GroupOpened(MenuPath([1, 1]))",
+                "Highlighted(2)"
+            ]
+        );
+
+        // disabled or missing item can't be highlighted
+        assert!(!menu_state.set_highlight_path(&MenuPath::new([1, 0])));
+        assert!(!menu_state.set_highlight_path(&MenuPath::new([2])));
+        assert_eq!(menu_state.highlight_path(), path);
+        assert!(menu_state.item_at(&MenuPath::new([1, 0])).is_some());
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut state = MenuState::new(vec![MenuItem::group(
+            "Window",
+            vec![
+                MenuItem::item("a", 1),
+                MenuItem::item("b", 2),
+                MenuItem::separator(),
+                MenuItem::group("c", vec![MenuItem::item("d", 4)]),
+            ],
+        )]);
+        state.set_highlight_path(&MenuPath::new([0, 3, 0]));
+
+        // insert before highlight keeps the item highlighted
+        assert!(state.insert(&MenuPath::new([0, 0]), MenuItem::item("z", 0)));
+        assert_eq!(state.highlight_path(), MenuPath::new([0, 4, 0]));
+        assert!(!state.insert(&MenuPath::new([0, 9]), MenuItem::item("y", 0)));
+
+        // move the open group, highlight follows
+        assert!(state.move_item(&MenuPath::new([0, 4]), &MenuPath::new([0, 0])));
+        assert_eq!(state.highlight_path(), MenuPath::new([0, 0, 0]));
+        assert!(!state.move_item(&MenuPath::new([0, 0]), &MenuPath::new([0, 0, 0])));
+
+        // replace keeps the highlight chain when still valid
+        state.replace(
+            &MenuPath::new([0, 0]),
+            MenuItem::group("&Tabs", vec![MenuItem::item("e", 5)]),
+        );
+        assert_eq!(state.highlight().unwrap().data, Some(5));
+        assert_eq!(
+            state.item_at(&MenuPath::new([0, 0])).unwrap().name(),
+            "Tabs"
+        );
+
+        // removing highlighted item highlights the next sibling
+        state.set_highlight_path(&MenuPath::new([0, 2]));
+        let removed = state.remove(&MenuPath::new([0, 2])).unwrap();
+        assert_eq!(removed.data, Some(1));
+        assert!(!removed.is_highlight);
+        assert_eq!(state.highlight().unwrap().data, Some(2));
+
+        // separators are skipped, previous sibling picked at the end
+        state.remove(&MenuPath::new([0, 2]));
+        assert_eq!(state.highlight().unwrap().data, Some(0));
+
+        assert!(state.set_name(&MenuPath::new([0, 1]), "&Zoom"));
+        assert!(state.set_data(&MenuPath::new([0, 1]), 9));
+        assert!(state.handle_char('z'));
+        assert_eq!(state.highlight().unwrap().data, Some(9));
+    }
+
+    #[test]
+    fn test_lazy() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        };
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let sender = Arc::new(Mutex::new(None));
+        let mut menu_state = MenuState::new(vec![
+            MenuItem::lazy("Hosts", {
+                let (loads, sender) = (loads.clone(), sender.clone());
+                move |s| {
+                    loads.fetch_add(1, Ordering::SeqCst);
+                    *sender.lock().unwrap() = Some(s);
+                }
+            }),
+            MenuItem::lazy("Branches", |s| {
+                s.send(vec![MenuItem::item("main", 9)]);
+            })
+            .cache(false),
+        ]);
+        let area = Rect::new(0, 0, 30, 5);
+        let render = |state: &mut MenuState<i32>| {
+            let mut buf = Buffer::empty(area);
+            Menu::new().render(area, &mut buf, state);
+            buf
+        };
+
+        // opening the group starts loading
+        menu_state.activate();
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(render(&mut menu_state)[(3, 2)].symbol(), "L");
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().name(), "Hosts");
+
+        // delivered from elsewhere, shown from next render
+        let s = sender.lock().unwrap().take().unwrap();
+        assert!(s.send(vec![MenuItem::item("alpha", 1), MenuItem::item("beta", 2)]));
+        assert_eq!(render(&mut menu_state)[(3, 2)].symbol(), "a");
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(1));
+
+        // cached on reopen
+        menu_state.reset();
+        menu_state.activate();
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+
+        // invalidated while open loads again right away
+        assert!(menu_state.invalidate(&MenuPath::new([0])));
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+        assert!(!menu_state.invalidate(&MenuPath::new([0, 0])));
+
+        // uncached group is loaded on every open, and synchronously
+        menu_state.right();
+        menu_state.down();
+        assert_eq!(menu_state.highlight().unwrap().data, Some(9));
+        menu_state.set_name(&MenuPath::new([1, 0]), "stale");
+        menu_state.left();
+        menu_state.right();
+        assert_eq!(
+            menu_state.item_at(&MenuPath::new([1, 0])).unwrap().name(),
+            "main"
+        );
     }
 }
@@ -0,0 +1,232 @@
+//! `menu!` macro, compact syntax for building menu trees
+
+/// Build `Vec<MenuItem<T>>` with compact syntax, it expands to
+/// [MenuItem](crate::MenuItem) constructors.
+///
+/// Entries are separated by `,`:
+///
+/// - `"name" => data` is [MenuItem::item](crate::MenuItem::item)
+/// - `"name" => [entries]` is [MenuItem::group](crate::MenuItem::group),
+///   wrap data in parentheses if it is an array
+/// - `---` is [MenuItem::separator](crate::MenuItem::separator)
+/// - `section "name"` is [MenuItem::section](crate::MenuItem::section)
+/// - `checkbox "name" => data`, or `checkbox(checked) "name" => data`, is
+///   [MenuItem::checkbox](crate::MenuItem::checkbox)
+/// - `radio(group) "name" => data`, or `radio(group, checked) "name" =>
+///   data`, is [MenuItem::radio](crate::MenuItem::radio)
+/// - `(item)` is a [MenuItem](crate::MenuItem) built elsewhere, e.g. a
+///   group of generated items
+///
+/// Entry can be preceded by attributes, `#[method(args)]` calls builder
+/// method of [MenuItem](crate::MenuItem), e.g. `#[shortcut("Ctrl+N")]`.
+///
+/// Malformed entry is a compile error pointing at it.
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{menu, MenuState};
+///
+/// let state = MenuState::new(menu![
+///     "&File" => [
+///         #[shortcut("Ctrl+N")]
+///         #[description("Start with an empty buffer")]
+///         "&New" => "new",
+///         "Open &recent" => [
+///             "file_1.txt" => "file_1",
+///         ],
+///         ---,
+///         "E&xit" => "exit",
+///     ],
+///     "&View" => [
+///         checkbox "&Word wrap" => "wrap",
+///         section "Theme",
+///         radio("theme") "&Light" => "light",
+///         radio("theme", true) "&Dark" => "dark",
+///     ],
+/// ]);
+///
+/// assert_eq!(state.find(|data| *data == "dark").unwrap().indices(), &[1, 3]);
+/// ```
+///
+/// Missing `=>` is reported at `"label_new"`:
+///
+/// ```compile_fail
+/// let items: Vec<tui_menu::MenuItem<&str>> = tui_menu::menu![
+///     "&New" "label_new",
+/// ];
+/// ```
+#[macro_export]
+macro_rules! menu {
+    // all entries are built
+    (@entries [$($items:expr,)*] []) => {
+        ::std::vec![$($items,)*]
+    };
+    (@entries [$($items:expr,)*] [$($attrs:tt)+]) => {
+        ::std::compile_error!(::std::concat!(
+            "menu attribute without entry: ",
+            ::std::stringify!($($attrs)+)
+        ))
+    };
+
+    // attribute, which is applied to next entry
+    (@entries [$($items:expr,)*] [$($attrs:tt)*] #[$($attr:tt)+] $($rest:tt)*) => {
+        $crate::menu!(@entries [$($items,)*] [$($attrs)* .$($attr)+] $($rest)*)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*] --- $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::separator() $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*]
+        section $name:literal $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::section($name) $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*]
+        checkbox $(($checked:expr))? $name:literal => $data:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::checkbox($name, $data, $crate::menu!(@or_false $($checked)?))
+                $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*]
+        radio($group:expr $(, $checked:expr)?) $name:literal => $data:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::radio($name, $group, $data, $crate::menu!(@or_false $($checked)?))
+                $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*]
+        $name:literal => [$($children:tt)*] $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::group($name, $crate::menu!($($children)*)) $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*]
+        $name:literal => $data:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $crate::MenuItem::item($name, $data) $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*] ($item:expr) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [
+            $($items,)*
+            $item $($attrs)*,
+        ] [] $($($rest)*)?)
+    };
+
+    (@entries [$($items:expr,)*] [$($attrs:tt)*] $($malformed:tt)+) => {
+        $crate::__menu_malformed!($($malformed)+)
+    };
+
+    (@or_false) => {
+        false
+    };
+    (@or_false $checked:expr) => {
+        $checked
+    };
+
+    ($($entries:tt)*) => {
+        $crate::menu!(@entries [] [] $($entries)*)
+    };
+}
+
+/// Report malformed entry of [menu!]. It fails to match here, so the
+/// error points at the unexpected token.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __menu_malformed {
+    (--- $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report ---)
+    };
+    (section $name:literal $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report section $name)
+    };
+    (checkbox $(($checked:expr))? $name:literal => $data:expr $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report checkbox $name => $data)
+    };
+    (radio($($args:tt)*) $name:literal => $data:expr $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report radio($($args)*) $name => $data)
+    };
+    ($name:literal => $data:expr $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report $name => $data)
+    };
+    (($item:expr) $(, $($rest:tt)*)?) => {
+        $crate::__menu_malformed!(@report ($item))
+    };
+    (@report $($entry:tt)*) => {
+        ::std::compile_error!(::std::concat!(
+            "malformed menu entry: ",
+            ::std::stringify!($($entry)*)
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MenuEvent, MenuItem, MenuPath, MenuState};
+
+    #[test]
+    fn test_menu_macro() {
+        let recent = ["a.txt", "b.txt"];
+        let items: Vec<MenuItem<i32>> = menu![
+            "&File" => [
+                #[shortcut("Ctrl+N")]
+                "&New" => 1,
+                "Open &recent" => [],
+                ---,
+                #[disabled(true)]
+                #[description("Not yet")]
+                "E&xit" => 2
+            ],
+            "&View" => [
+                checkbox "&Wrap" => 3,
+                checkbox(true) "&Numbers" => 4,
+                section "Theme",
+                radio("theme") "&Light" => 5,
+                radio("theme", true) "&Dark" => 6,
+            ],
+            "Count" => (recent.len() as i32 * 10),
+            (MenuItem::group("Generated", vec![MenuItem::item("x", 7)])),
+        ];
+        let mut state = MenuState::new(items);
+
+        let exit = state.item_at(&MenuPath::new([0, 3])).unwrap();
+        assert!(!exit.is_enabled());
+        assert_eq!(exit.get_description(), Some("Not yet"));
+        // empty group is a plain item
+        assert!(!state.item_at(&MenuPath::new([0, 1])).unwrap().is_group());
+        assert_eq!(state.is_checked(|d| *d == 3), Some(false));
+        assert_eq!(state.is_checked(|d| *d == 4), Some(true));
+        assert_eq!(state.is_checked(|d| *d == 6), Some(true));
+        assert_eq!(state.find(|d| *d == 2), Some(MenuPath::new([0, 3])));
+        assert_eq!(state.find(|d| *d == 20), Some(MenuPath::new([2])));
+        assert_eq!(state.find(|d| *d == 7), Some(MenuPath::new([3, 0])));
+
+        assert!(state.dispatch_shortcut("Ctrl+N"));
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+    }
+}
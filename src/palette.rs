@@ -0,0 +1,501 @@
+//! Command palette, fuzzy search over every item of a [MenuState]
+
+use crate::{fold_case, MenuItem, MenuState};
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Margin, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+use ratatui_widgets::{block::Block, borders::Borders, clear::Clear};
+
+/// separator between names in breadcrumb
+const BREADCRUMB_SEPARATOR: &str = " › ";
+
+/// The state for [CommandPalette], keep track of query and selection
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{MenuEvent, MenuItem, MenuState, PaletteState};
+///
+/// let mut menu = MenuState::<&'static str>::new(vec![
+///     MenuItem::group("File", vec![
+///         MenuItem::item("Open", "label_open"),
+///         MenuItem::item("Save as", "label_save_as"),
+///     ]),
+/// ]);
+///
+/// let mut palette = PaletteState::new();
+/// palette.open();
+/// "sav".chars().for_each(|c| palette.push_char(c));
+/// assert!(palette.confirm(&mut menu));
+/// assert!(matches!(menu.drain_events().last(), Some(MenuEvent::Selected("label_save_as"))));
+/// ```
+#[derive(Debug, Default)]
+pub struct PaletteState {
+    open: bool,
+    query: String,
+    /// index of selected entry in ranked matches
+    selected: usize,
+    /// index of first entry shown
+    offset: usize,
+    /// matches shown in last render, used to keep selection in range
+    count: Option<usize>,
+}
+
+impl PaletteState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// open palette with empty query
+    pub fn open(&mut self) {
+        *self = Self {
+            open: true,
+            ..Self::default()
+        };
+    }
+
+    /// close palette
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// whether palette is open, app should route key events to it
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// current query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// append char to query, selection goes back to best match
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// remove last char of query, e.g. on backspace
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// select prev entry
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// select next entry
+    pub fn down(&mut self) {
+        let last = self
+            .count
+            .map_or(usize::MAX, |count| count.saturating_sub(1));
+        self.selected = (self.selected + 1).min(last);
+    }
+
+    /// activate selected entry in `menu` and close palette. It is the same
+    /// as choosing the item through drop downs, e.g. [crate::MenuEvent::Selected]
+    /// is emitted by `menu`.
+    ///
+    /// Return: true if any entry matched query
+    pub fn confirm<T: Clone>(&mut self, menu: &mut MenuState<T>) -> bool {
+        let entries = ranked_entries(&menu.root_item, &self.query);
+        let Some(entry) = entries.get(self.selected.min(entries.len().saturating_sub(1))) else {
+            return false;
+        };
+        let path = entry.path.clone();
+
+        self.close();
+        menu.activate_at(&path);
+        true
+    }
+}
+
+/// Widget of command palette, lists every item of [MenuState] with its
+/// breadcrumb, e.g. "File › Open recent › file_1.txt", filtered by fuzzy
+/// matching the query and ranked by score.
+///
+/// Nothing is drawn when [PaletteState] is not open.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+/// use tui_menu::{CommandPalette, MenuItem, MenuState, PaletteState};
+///
+/// let menu = MenuState::<&'static str>::new(vec![
+///     MenuItem::group("File", vec![MenuItem::item("Open", "label_open")]),
+/// ]);
+/// let mut palette = PaletteState::new();
+/// palette.open();
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let mut buf = Buffer::empty(area);
+/// CommandPalette::new(&menu).render(area, &mut buf, &mut palette);
+/// ```
+pub struct CommandPalette<'a, T> {
+    menu: &'a MenuState<T>,
+    /// style for default entry style
+    default_style: Style,
+    /// style for selected entry
+    highlight_style: Style,
+    /// style patched on matched chars
+    match_style: Style,
+    /// style for disabled entry
+    disabled_style: Style,
+    /// max width of palette
+    width: u16,
+    /// max entries shown at once
+    max_rows: u16,
+}
+
+impl<'a, T> CommandPalette<'a, T> {
+    pub fn new(menu: &'a MenuState<T>) -> Self {
+        Self {
+            menu,
+            default_style: Style::default().fg(Color::White).bg(Color::DarkGray),
+            highlight_style: Style::default().fg(Color::White).bg(Color::LightBlue),
+            match_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            disabled_style: Style::default().fg(Color::Gray).bg(Color::DarkGray),
+            width: 60,
+            max_rows: 10,
+        }
+    }
+
+    /// update with default style
+    pub fn default_style(mut self, style: Style) -> Self {
+        self.default_style = style;
+        self
+    }
+
+    /// update with highlight style
+    pub fn highlight(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// update style of matched chars, it is patched on entry style
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// update with disabled style
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// update max width, default is 60
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// update max entries shown at once, default is 10
+    pub fn max_rows(mut self, rows: u16) -> Self {
+        self.max_rows = rows;
+        self
+    }
+
+    /// spans of entry label, matched chars are patched with match style
+    fn label_spans(&self, entry: &Entry, style: Style) -> Vec<Span<'static>> {
+        let mut spans = vec![];
+        let mut run = String::new();
+        let mut run_matched = false;
+        for (idx, c) in entry.label.chars().enumerate() {
+            let matched = entry.matched.contains(&idx);
+            if matched != run_matched && !run.is_empty() {
+                spans.push(self.run_span(std::mem::take(&mut run), run_matched, style));
+            }
+            run_matched = matched;
+            run.push(c);
+        }
+        if !run.is_empty() {
+            spans.push(self.run_span(run, run_matched, style));
+        }
+        spans
+    }
+
+    fn run_span(&self, run: String, matched: bool, style: Style) -> Span<'static> {
+        if matched {
+            Span::styled(run, style.patch(self.match_style))
+        } else {
+            Span::styled(run, style)
+        }
+    }
+}
+
+impl<T> StatefulWidget for CommandPalette<'_, T> {
+    type State = PaletteState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.open {
+            return;
+        }
+        let area = area.clamp(*buf.area());
+
+        let entries = ranked_entries(&self.menu.root_item, &state.query);
+        state.count = Some(entries.len());
+        state.selected = state.selected.min(entries.len().saturating_sub(1));
+
+        // query line, then entries, in border
+        let rows = (entries.len() as u16).clamp(1, self.max_rows.max(1));
+        let width = self.width.min(area.width);
+        let palette = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + 1.min(area.height),
+            width,
+            rows + 3,
+        )
+        .intersection(area);
+
+        Clear.render(palette, buf);
+        buf.set_style(palette, self.default_style);
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Commands ")
+            .style(self.default_style)
+            .render(palette, buf);
+
+        let inner = palette.inner(Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        if inner.is_empty() {
+            return;
+        }
+        buf.set_line(
+            inner.x,
+            inner.y,
+            &Line::from(vec![Span::raw("> "), Span::raw(state.query.as_str())])
+                .style(self.default_style),
+            inner.width,
+        );
+
+        if entries.is_empty() {
+            buf.set_stringn(
+                inner.x + 1,
+                inner.y + 1,
+                "no matching item",
+                inner.width.saturating_sub(1) as usize,
+                self.default_style.add_modifier(Modifier::DIM),
+            );
+            return;
+        }
+
+        // keep selection in view
+        let visible = inner.height.saturating_sub(1) as usize;
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + visible {
+            state.offset = state.selected + 1 - visible;
+        }
+        state.offset = state.offset.min(entries.len().saturating_sub(visible));
+
+        for (row, (idx, entry)) in entries
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(visible)
+            .enumerate()
+        {
+            let style = if !entry.enabled {
+                self.disabled_style
+            } else if idx == state.selected {
+                self.highlight_style
+            } else {
+                self.default_style
+            };
+
+            let mut spans = vec![Span::styled(" ", style)];
+            spans.extend(self.label_spans(entry, style));
+            let shortcut = entry.shortcut.unwrap_or_default();
+            let padding = (inner.width as usize)
+                .saturating_sub(Span::raw(&entry.label).width() + 1)
+                .saturating_sub(Span::raw(shortcut).width() + 1);
+            spans.push(Span::styled(" ".repeat(padding), style));
+            spans.push(Span::styled(shortcut, style));
+            spans.push(Span::styled(" ", style));
+
+            buf.set_line(
+                inner.x,
+                inner.y + 1 + row as u16,
+                &Line::from(spans),
+                inner.width,
+            );
+        }
+    }
+}
+
+/// An item in palette, with match info against the query
+struct Entry<'a> {
+    /// index path from root
+    path: Vec<usize>,
+    /// names from root to item, joined by [BREADCRUMB_SEPARATOR]
+    label: String,
+    shortcut: Option<&'a str>,
+    enabled: bool,
+    score: i64,
+    /// char indices in label matched by query
+    matched: Vec<usize>,
+}
+
+/// entries matching `query`, best first. Ties keep the tree order
+fn ranked_entries<'a, T>(root: &'a MenuItem<T>, query: &str) -> Vec<Entry<'a>> {
+    let mut entries = vec![];
+    collect_entries(root, &mut vec![], &mut vec![], &mut entries);
+
+    let mut entries: Vec<_> = entries
+        .into_iter()
+        .filter_map(|mut entry| {
+            let (score, matched) = fuzzy_match(query, &entry.label)?;
+            entry.score = score;
+            entry.matched = matched;
+            Some(entry)
+        })
+        .collect();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+    entries
+}
+
+/// collect items can be activated, groups which can't be opened are skipped
+fn collect_entries<'a, T>(
+    item: &'a MenuItem<T>,
+    path: &mut Vec<usize>,
+    names: &mut Vec<&'a str>,
+    entries: &mut Vec<Entry<'a>>,
+) {
+    for (idx, child) in item.children.iter().enumerate() {
+        if child.is_decoration() || (child.is_group() && !child.is_enabled()) {
+            continue;
+        }
+        path.push(idx);
+        names.push(child.name());
+
+        if child.is_group() {
+            collect_entries(child, path, names, entries);
+        } else if child.data.is_some() {
+            entries.push(Entry {
+                path: path.clone(),
+                label: names.join(BREADCRUMB_SEPARATOR),
+                shortcut: child.shortcut.as_deref(),
+                enabled: child.is_enabled(),
+                score: 0,
+                matched: vec![],
+            });
+        }
+
+        path.pop();
+        names.pop();
+    }
+}
+
+/// match `query` as a case insensitive subsequence of `text`, spaces in
+/// query are ignored. Consecutive matches and matches at word start score
+/// higher, gaps score lower.
+///
+/// Return: score and char indices of matches, None if not matched
+fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let text: Vec<char> = text.chars().collect();
+    let mut matched = vec![];
+    let mut score = 0;
+    let mut pos = 0;
+
+    for q in query.chars().filter(|c| !c.is_whitespace()).map(fold_case) {
+        let idx = (pos..text.len()).find(|&idx| fold_case(text[idx]) == q)?;
+
+        score += 1;
+        if idx == 0 || !text[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+        match matched.last() {
+            Some(&last) if last + 1 == idx => score += 5,
+            Some(_) => score -= (idx - pos).min(3) as i64,
+            None => {}
+        }
+
+        matched.push(idx);
+        pos = idx + 1;
+    }
+    Some((score, matched))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_match, ranked_entries, PaletteState};
+    use crate::{MenuEvent, MenuState};
+
+    type MenuItem = crate::MenuItem<i32>;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("", "File"), Some((0, vec![])));
+        assert_eq!(fuzzy_match("fo", "File › Open").unwrap().1, vec![0, 7]);
+        assert_eq!(
+            fuzzy_match("OPEN", "File › Open").unwrap().1,
+            vec![7, 8, 9, 10]
+        );
+        assert_eq!(fuzzy_match("x", "File › Open"), None);
+
+        // consecutive beats scattered
+        let (consecutive, _) = fuzzy_match("op", "Open").unwrap();
+        let (scattered, _) = fuzzy_match("op", "Outer pane").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn test_palette() {
+        let mut menu = MenuState::new(vec![
+            MenuItem::group(
+                "File",
+                vec![
+                    MenuItem::item("Open", 0),
+                    MenuItem::group("Open recent", vec![MenuItem::item("file_1.txt", 1)]),
+                    MenuItem::separator(),
+                    MenuItem::item("Exit", 2),
+                ],
+            ),
+            MenuItem::group("Disabled", vec![MenuItem::item("Hidden", 3)]).disabled(true),
+        ]);
+
+        let labels = |query: &str| -> Vec<String> {
+            ranked_entries(&menu.root_item, query)
+                .into_iter()
+                .map(|entry| entry.label)
+                .collect()
+        };
+        assert_eq!(
+            labels(""),
+            vec![
+                "File › Open",
+                "File › Open recent › file_1.txt",
+                "File › Exit"
+            ]
+        );
+        assert_eq!(labels("file1"), vec!["File › Open recent › file_1.txt"]);
+
+        // selection is activated like choosing it in drop down
+        let mut palette = PaletteState::new();
+        palette.open();
+        palette.push_char('o');
+        palette.down();
+        assert!(palette.confirm(&mut menu));
+        assert!(!palette.is_open());
+        assert!(matches!(
+            menu.drain_events().last(),
+            Some(MenuEvent::Selected(1))
+        ));
+
+        palette.open();
+        palette.push_char('z');
+        assert!(!palette.confirm(&mut menu));
+    }
+}
@@ -0,0 +1,425 @@
+//! Serialize and deserialize menu trees, enabled by `serde` feature
+
+use crate::{MenuItem, MenuItemKind, MenuPath, MenuState};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_path_to_error::Segment;
+use std::{borrow::Cow, fmt};
+
+/// Row kind in serialized form, default is a normal item or group
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Kind {
+    #[default]
+    Item,
+    Separator,
+    Section,
+    Checkbox,
+    Radio,
+}
+
+/// serialized form of [MenuItem], borrowed from it
+#[derive(Serialize)]
+struct ItemRef<'a, T> {
+    #[serde(skip_serializing_if = "str::is_empty")]
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<&'a T>,
+    #[serde(skip_serializing_if = "is_item")]
+    kind: Kind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    radio_group: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mnemonic: Option<char>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shortcut: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "is_false")]
+    checked: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    disabled: bool,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    children: &'a [MenuItem<T>],
+}
+
+/// serialized form of [MenuItem]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ItemDef<T> {
+    #[serde(default)]
+    name: String,
+    #[serde(default = "Option::default")]
+    data: Option<T>,
+    #[serde(default)]
+    kind: Kind,
+    #[serde(default)]
+    radio_group: Option<String>,
+    #[serde(default)]
+    mnemonic: Option<char>,
+    #[serde(default)]
+    shortcut: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    checked: bool,
+    #[serde(default)]
+    disabled: bool,
+    #[serde(default = "Vec::new")]
+    children: Vec<MenuItem<T>>,
+}
+
+/// serialized form of [MenuState]
+#[derive(Serialize)]
+struct StateRef<'a, T> {
+    items: &'a [MenuItem<T>],
+}
+
+/// serialized form of [MenuState]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StateDef<T> {
+    items: Vec<MenuItem<T>>,
+}
+
+fn is_item(kind: &Kind) -> bool {
+    *kind == Kind::Item
+}
+
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
+impl<T> MenuItem<T> {
+    /// name with `&` escaped and mnemonic marked, and the mnemonic if it
+    /// can't be marked in name
+    fn marked_name(&self) -> (String, Option<char>) {
+        let mut name = String::with_capacity(self.name.len() + 1);
+        let mut marked = false;
+        for (idx, c) in self.name.chars().enumerate() {
+            if c == '&' {
+                name.push('&');
+            } else if self.mnemonic_index == Some(idx) && c.is_alphanumeric() {
+                name.push('&');
+                marked = true;
+            }
+            name.push(c);
+        }
+        (name, self.mnemonic.filter(|_| !marked))
+    }
+}
+
+impl<T: Serialize> Serialize for MenuItem<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (name, mnemonic) = self.marked_name();
+        let (kind, radio_group) = match &self.kind {
+            MenuItemKind::Normal => (Kind::Item, None),
+            MenuItemKind::Separator => (Kind::Separator, None),
+            MenuItemKind::Section => (Kind::Section, None),
+            MenuItemKind::Checkbox => (Kind::Checkbox, None),
+            MenuItemKind::Radio(group) => (Kind::Radio, Some(group.as_ref())),
+        };
+        // lazy group is loaded again after deserialized, don't save the
+        // placeholder
+        let children = match &self.lazy {
+            Some(lazy) if !lazy.loaded => &[],
+            _ => self.children.as_slice(),
+        };
+
+        ItemRef {
+            name,
+            data: self.data.as_ref(),
+            kind,
+            radio_group,
+            mnemonic,
+            shortcut: self.shortcut.as_deref(),
+            description: self.description.as_deref(),
+            checked: self.checked,
+            disabled: self.disabled,
+            children,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MenuItem<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ItemDef::deserialize(deserializer)?
+            .into_item()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl<T> ItemDef<T> {
+    fn into_item(self) -> Result<MenuItem<T>, &'static str> {
+        if self.kind != Kind::Radio && self.radio_group.is_some() {
+            return Err("only radio item has `radio_group`");
+        }
+        if !matches!(self.kind, Kind::Checkbox | Kind::Radio) && self.checked {
+            return Err("only checkbox or radio item can be `checked`");
+        }
+        if self.kind != Kind::Item && !self.children.is_empty() {
+            return Err("only item can have `children`");
+        }
+
+        let item = match self.kind {
+            Kind::Item => MenuItem::new(self.name, self.data, self.children),
+            Kind::Separator => MenuItem::separator(),
+            Kind::Section => MenuItem::section(self.name),
+            Kind::Checkbox => {
+                let data = self.data.ok_or("checkbox item needs `data`")?;
+                MenuItem::checkbox(self.name, data, self.checked)
+            }
+            Kind::Radio => {
+                let group = self.radio_group.ok_or("radio item needs `radio_group`")?;
+                let data = self.data.ok_or("radio item needs `data`")?;
+                MenuItem::radio(self.name, group, data, self.checked)
+            }
+        };
+
+        let mut item = item.disabled(self.disabled);
+        if let Some(c) = self.mnemonic {
+            item = item.mnemonic(c);
+        }
+        item.shortcut = self.shortcut.map(Cow::Owned);
+        item.description = self.description.map(Cow::Owned);
+        Ok(item)
+    }
+}
+
+impl<T: Serialize> Serialize for MenuState<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StateRef {
+            items: &self.root_item.children,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Clone + Deserialize<'de>> Deserialize<'de> for MenuState<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        StateDef::deserialize(deserializer).map(|state| MenuState::new(state.items))
+    }
+}
+
+impl<T: Clone> MenuState<T> {
+    /// deserialize menu tree, like [Deserialize::deserialize], and report
+    /// which item is wrong when it fails. Serialized form is a table of
+    /// `items`, check serialized [MenuState] for the fields.
+    ///
+    /// Predicates of [MenuItem::enabled_when] and loaders of
+    /// [MenuItem::lazy] are code, so they are not serialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tui_menu::{MenuPath, MenuState};
+    ///
+    /// let json = r#"{ "items": [
+    ///     { "name": "&File", "children": [
+    ///         { "name": "&New", "data": "new", "shortcut": "Ctrl+N" },
+    ///         { "kind": "separator" },
+    ///         { "name": "E&xit", "data": 1 }
+    ///     ] }
+    /// ] }"#;
+    ///
+    /// let mut de = serde_json::Deserializer::from_str(json);
+    /// let err = MenuState::<String>::from_deserializer(&mut de).err().unwrap();
+    /// assert_eq!(err.item(), &MenuPath::new([0, 2]));
+    /// assert_eq!(err.field(), Some("data"));
+    /// ```
+    pub fn from_deserializer<'de, D>(deserializer: D) -> Result<Self, LoadError<D::Error>>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        serde_path_to_error::deserialize(deserializer).map_err(LoadError::new)
+    }
+}
+
+/// Error of [MenuState::from_deserializer], with the path to offending
+/// item
+#[derive(Debug)]
+pub struct LoadError<E> {
+    item: MenuPath,
+    field: Option<String>,
+    source: E,
+}
+
+impl<E> LoadError<E> {
+    fn new(err: serde_path_to_error::Error<E>) -> Self {
+        let mut item = vec![];
+        let mut field = None;
+        let mut segments = err.path().iter().peekable();
+        while let Some(segment) = segments.next() {
+            match (segment, segments.peek()) {
+                (Segment::Map { key }, Some(Segment::Seq { index }))
+                    if key == "items" || key == "children" =>
+                {
+                    item.push(*index);
+                    field = None;
+                    segments.next();
+                }
+                // outermost field of the item
+                (Segment::Map { key }, _) if field.is_none() => field = Some(key.clone()),
+                _ => {}
+            }
+        }
+
+        Self {
+            item: MenuPath::new(item),
+            field,
+            source: err.into_inner(),
+        }
+    }
+
+    /// path to the offending item, empty if it is not in any item
+    pub fn item(&self) -> &MenuPath {
+        &self.item
+    }
+
+    /// offending field of the item
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// the underlying deserializer error
+    pub fn into_inner(self) -> E {
+        self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for LoadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.item.is_empty() {
+            write!(f, "item {:?}", self.item.indices())?;
+            if let Some(field) = &self.field {
+                write!(f, " field `{field}`")?;
+            }
+            f.write_str(": ")?;
+        }
+        self.source.fmt(f)
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for LoadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MenuEvent, MenuItem, MenuPath, MenuState};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Action {
+        New,
+        Open(String),
+        Wrap,
+        Light,
+        Dark,
+    }
+
+    #[test]
+    fn test_serde() {
+        let toml = r#"
+            [[items]]
+            name = "&File"
+
+            [[items.children]]
+            name = "&New"
+            data = "New"
+            shortcut = "Ctrl+N"
+            description = "Start with an empty buffer"
+
+            [[items.children]]
+            kind = "separator"
+
+            [[items.children]]
+            name = "Save && close"
+            mnemonic = "c"
+            disabled = true
+
+            [[items]]
+            name = "&View"
+
+            [[items.children]]
+            kind = "checkbox"
+            name = "&Word wrap"
+            data = "Wrap"
+
+            [[items.children]]
+            kind = "section"
+            name = "Theme"
+
+            [[items.children]]
+            kind = "radio"
+            name = "&Light"
+            radio_group = "theme"
+            data = "Light"
+
+            [[items.children]]
+            kind = "radio"
+            name = "&Dark"
+            radio_group = "theme"
+            data = "Dark"
+            checked = true
+        "#;
+
+        let mut state: MenuState<Action> = toml::from_str(toml).unwrap();
+        let save = state.item_at(&MenuPath::new([0, 2])).unwrap();
+        assert_eq!(save.name(), "Save & close");
+        assert!(!save.is_enabled());
+        assert_eq!(state.is_checked(|a| *a == Action::Dark), Some(true));
+
+        state.activate();
+        assert!(state.handle_char('n'));
+        assert_eq!(
+            state.highlight_description(),
+            Some("Start with an empty buffer")
+        );
+        assert!(matches!(
+            state.drain_events().last(),
+            Some(MenuEvent::Selected(Action::New))
+        ));
+
+        // round trip
+        let serialized = toml::to_string(&state).unwrap();
+        let reloaded: MenuState<Action> = toml::from_str(&serialized).unwrap();
+        assert_eq!(toml::to_string(&reloaded).unwrap(), serialized);
+
+        let json =
+            serde_json::to_string(&MenuItem::item("&Open", Action::Open("a".into()))).unwrap();
+        assert_eq!(json, r#"{"name":"&Open","data":{"Open":"a"}}"#);
+    }
+
+    #[test]
+    fn test_load_error() {
+        let load = |json: &str| {
+            let mut de = serde_json::Deserializer::from_str(json);
+            MenuState::<Action>::from_deserializer(&mut de)
+                .err()
+                .unwrap()
+        };
+
+        let err = load(r#"{ "items": [{ "name": "File", "children": [{ "data": "Nope" }] }] }"#);
+        assert_eq!(err.item(), &MenuPath::new([0, 0]));
+        assert_eq!(err.field(), Some("data"));
+        assert!(err
+            .to_string()
+            .starts_with("item [0, 0] field `data`: unknown variant"));
+
+        let err = load(r#"{ "items": [{}, { "kind": "radio", "data": "Dark" }] }"#);
+        assert_eq!(err.item(), &MenuPath::new([1]));
+        assert!(err.to_string().contains("radio item needs `radio_group`"));
+
+        let err = load(r#"{ "items": [{ "nmae": "File" }] }"#);
+        assert_eq!(err.item(), &MenuPath::new([0]));
+        assert!(err.to_string().contains("unknown field `nmae`"));
+
+        let err = load(r#"{}"#);
+        assert!(err.item().is_empty());
+        assert_eq!(err.to_string(), "missing field `items` at line 1 column 2");
+    }
+}
@@ -0,0 +1,392 @@
+/*! Derive macro of [tui-menu](https://crates.io/crates/tui-menu).
+
+Use it with `derive` feature of tui-menu, which re-exports [macro@Menu].
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitInt, LitStr, Variant};
+
+/// Implement `tui_menu::MenuTree` for an enum, each unit variant is a menu
+/// item with the variant as data.
+///
+/// Variants are configured by `#[menu(..)]`:
+///
+/// - `label = "&New"`, name of the item, default is words of variant
+///   name, e.g. "Save as" for `SaveAs`
+/// - `group = "&File/Open &recent"`, path of groups the item is in,
+///   separated by `/`. Default is the top level
+/// - `order = 1`, items are sorted by it, then by declaration. Default is
+///   0. Group is placed where its first item is
+/// - `shortcut = "Ctrl+N"` and `description = ".."`, like the builder
+///   methods of `MenuItem`
+/// - `separator`, put a separator before the item
+/// - `skip`, not in the menu. Variants with fields must be skipped
+///
+/// Duplicate labels in one group are compile errors, `&` is ignored when
+/// comparing.
+///
+/// # Example
+///
+/// ```
+/// use tui_menu::{Menu, MenuState, MenuTree};
+///
+/// #[derive(Debug, Clone, PartialEq, Menu)]
+/// enum Action {
+///     #[menu(group = "&File", label = "&New", shortcut = "Ctrl+N")]
+///     FileNew,
+///     #[menu(group = "&File", label = "E&xit", separator, order = 1)]
+///     Exit,
+///     #[menu(group = "&File", label = "Save &as")]
+///     FileSaveAs,
+///     #[menu(group = "&Edit", label = "&Copy")]
+///     EditCopy,
+///     #[menu(skip)]
+///     FileOpenRecent(String),
+/// }
+///
+/// let state = MenuState::new(Action::menu_tree());
+/// let exit = state.find(|action| *action == Action::Exit).unwrap();
+/// assert_eq!(exit.indices(), &[0, 3]);
+/// ```
+///
+/// Duplicate label:
+///
+/// ```compile_fail
+/// #[derive(Clone, tui_menu::Menu)]
+/// enum Action {
+///     #[menu(label = "&Open")]
+///     Open,
+///     #[menu(label = "Op&en")]
+///     OpenFile,
+/// }
+/// ```
+#[proc_macro_derive(Menu, attributes(menu))]
+pub fn derive_menu(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// menu item of one variant
+struct Entry {
+    variant: Ident,
+    label: String,
+    group: Vec<String>,
+    order: i64,
+    shortcut: Option<String>,
+    description: Option<String>,
+    separator: bool,
+}
+
+/// node of menu tree being built
+enum Node<'a> {
+    Group {
+        name: String,
+        children: Vec<Node<'a>>,
+    },
+    Item(&'a Entry),
+    Separator,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Menu can only be derived for enums",
+        ));
+    };
+
+    let mut entries = vec![];
+    for variant in &data.variants {
+        if let Some(entry) = parse_entry(variant)? {
+            entries.push(entry);
+        }
+    }
+    // stable, so declaration order is kept
+    entries.sort_by_key(|entry| entry.order);
+
+    let mut root = vec![];
+    for entry in &entries {
+        insert(&mut root, entry)?;
+    }
+
+    let ident = &input.ident;
+    let items = root.iter().map(|node| node_tokens(ident, node));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tui_menu::MenuTree for #ident #ty_generics #where_clause {
+            fn menu_tree() -> ::std::vec::Vec<::tui_menu::MenuItem<Self>> {
+                ::std::vec![#(#items),*]
+            }
+        }
+    })
+}
+
+fn parse_entry(variant: &Variant) -> syn::Result<Option<Entry>> {
+    let mut entry = Entry {
+        variant: variant.ident.clone(),
+        label: words(&variant.ident.to_string()),
+        group: vec![],
+        order: 0,
+        shortcut: None,
+        description: None,
+        separator: false,
+    };
+    let mut skip = false;
+
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("menu"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("label") {
+                entry.label = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("group") {
+                let group = meta.value()?.parse::<LitStr>()?;
+                entry.group = group.value().split('/').map(|g| g.trim().into()).collect();
+                if entry.group.iter().any(String::is_empty) {
+                    return Err(Error::new_spanned(group, "empty group name"));
+                }
+            } else if meta.path.is_ident("order") {
+                entry.order = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+            } else if meta.path.is_ident("shortcut") {
+                entry.shortcut = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("description") {
+                entry.description = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("separator") {
+                entry.separator = true;
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+            } else {
+                return Err(meta.error(
+                    "unknown menu attribute, expected `label`, `group`, `order`, \
+                     `shortcut`, `description`, `separator` or `skip`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    if skip {
+        return Ok(None);
+    }
+    if !matches!(variant.fields, Fields::Unit) {
+        return Err(Error::new_spanned(
+            &variant.fields,
+            "menu item can't have fields, mark it with `#[menu(skip)]`",
+        ));
+    }
+    Ok(Some(entry))
+}
+
+/// insert entry under its group, groups are created as needed
+fn insert<'a>(mut nodes: &mut Vec<Node<'a>>, entry: &'a Entry) -> syn::Result<()> {
+    // `depth` is how many groups of path the duplicate is in
+    let duplicate = |name: &str, depth: usize, other: &str| {
+        let group = entry.group[..depth].join("/");
+        let place = if group.is_empty() {
+            "top level".to_string()
+        } else {
+            format!("group `{group}`")
+        };
+        Error::new_spanned(
+            &entry.variant,
+            format!("duplicate menu label `{name}` in {place}, also used by {other}"),
+        )
+    };
+
+    for (depth, group) in entry.group.iter().enumerate() {
+        let key = strip_mnemonic(group);
+        let idx = match nodes
+            .iter()
+            .position(|node| node.key().as_ref() == Some(&key))
+        {
+            Some(idx) => idx,
+            None => {
+                nodes.push(Node::Group {
+                    name: group.clone(),
+                    children: vec![],
+                });
+                nodes.len() - 1
+            }
+        };
+        nodes = match &mut nodes[idx] {
+            Node::Group { children, .. } => children,
+            Node::Item(item) => {
+                return Err(duplicate(group, depth, &format!("`{}`", item.variant)));
+            }
+            Node::Separator => unreachable!("separator has no key"),
+        };
+    }
+
+    let key = strip_mnemonic(&entry.label);
+    let depth = entry.group.len();
+    match nodes.iter().find(|node| node.key().as_ref() == Some(&key)) {
+        Some(Node::Item(item)) => {
+            return Err(duplicate(
+                &entry.label,
+                depth,
+                &format!("`{}`", item.variant),
+            ));
+        }
+        Some(_) => return Err(duplicate(&entry.label, depth, "a group")),
+        None => {}
+    }
+
+    if entry.separator {
+        nodes.push(Node::Separator);
+    }
+    nodes.push(Node::Item(entry));
+    Ok(())
+}
+
+impl Node<'_> {
+    /// name to find duplicates
+    fn key(&self) -> Option<String> {
+        match self {
+            Node::Group { name, .. } => Some(strip_mnemonic(name)),
+            Node::Item(entry) => Some(strip_mnemonic(&entry.label)),
+            Node::Separator => None,
+        }
+    }
+}
+
+fn node_tokens(ident: &Ident, node: &Node) -> TokenStream2 {
+    match node {
+        Node::Group { name, children } => {
+            let children = children.iter().map(|node| node_tokens(ident, node));
+            quote! {
+                ::tui_menu::MenuItem::group(#name, ::std::vec![#(#children),*])
+            }
+        }
+        Node::Item(entry) => {
+            let Entry {
+                variant,
+                label,
+                shortcut,
+                description,
+                ..
+            } = entry;
+            let shortcut = shortcut.iter();
+            let description = description.iter();
+            quote! {
+                ::tui_menu::MenuItem::item(#label, #ident::#variant)
+                    #(.shortcut(#shortcut))*
+                    #(.description(#description))*
+            }
+        }
+        Node::Separator => quote! { ::tui_menu::MenuItem::separator() },
+    }
+}
+
+/// name without `&` mnemonic marker
+fn strip_mnemonic(name: &str) -> String {
+    let mut stripped = String::with_capacity(name.len());
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' && chars.peek() == Some(&'&') {
+            chars.next();
+        } else if c == '&' {
+            continue;
+        }
+        stripped.push(c);
+    }
+    stripped
+}
+
+/// "SaveAs" to "Save as"
+fn words(ident: &str) -> String {
+    let mut label = String::with_capacity(ident.len() + 4);
+    for (idx, c) in ident.chars().enumerate() {
+        if c == '_' {
+            label.push(' ');
+        } else if idx > 0 && c.is_uppercase() {
+            if !label.ends_with(' ') {
+                label.push(' ');
+            }
+            label.extend(c.to_lowercase());
+        } else {
+            label.push(c);
+        }
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, strip_mnemonic, words};
+    use syn::{parse_quote, DeriveInput};
+
+    fn expand_err(input: DeriveInput) -> String {
+        expand(&input).err().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_derive() {
+        assert_eq!(words("SaveAs"), "Save as");
+        assert_eq!(words("Exit"), "Exit");
+        assert_eq!(strip_mnemonic("Save && &close"), "Save & close");
+
+        let tokens = expand(&parse_quote! {
+            enum Action {
+                #[menu(group = "&File", label = "E&xit", order = 1)]
+                Exit,
+                #[menu(group = "&File / Open &recent", shortcut = "Ctrl+R")]
+                Last,
+                #[menu(label = "&About")]
+                About,
+            }
+        })
+        .unwrap()
+        .to_string();
+        let file = tokens.find("\"&File\"").unwrap();
+        assert!(file < tokens.find("\"Open &recent\"").unwrap());
+        assert!(tokens.find("\"Last\"").unwrap() < tokens.find("\"E&xit\"").unwrap());
+        assert!(tokens.find("\"E&xit\"").unwrap() < tokens.find("\"&About\"").unwrap());
+        assert!(tokens.contains(". shortcut (\"Ctrl+R\")"));
+
+        let err = expand_err(parse_quote! {
+            enum Action {
+                #[menu(group = "File", label = "&Open")]
+                Open,
+                #[menu(group = "&File", label = "Op&en")]
+                OpenFile,
+            }
+        });
+        assert_eq!(
+            err,
+            "duplicate menu label `Op&en` in group `&File`, also used by `Open`"
+        );
+
+        let err = expand_err(parse_quote! {
+            enum Action {
+                File,
+                #[menu(group = "File")]
+                New,
+            }
+        });
+        assert_eq!(
+            err,
+            "duplicate menu label `File` in top level, also used by `File`"
+        );
+
+        let err = expand_err(parse_quote! {
+            enum Action {
+                Open(String),
+            }
+        });
+        assert!(err.contains("#[menu(skip)]"));
+
+        let err = expand_err(parse_quote! {
+            enum Action {
+                #[menu(lable = "Open")]
+                Open,
+            }
+        });
+        assert!(err.starts_with("unknown menu attribute"));
+    }
+}